@@ -1,19 +1,44 @@
+use crate::config::expand_home;
+use crate::editor::EditorRequest;
+use crate::jira::adf::adf_to_markdown;
+use crate::jira::agile::{is_agile_unavailable, Sprint};
+use crate::jira::auth::{jira_authentication, oauth_authentication};
+use crate::jira::oauth::OAuthSession;
 use crate::jira::projects::Project;
-use crate::jira::tickets::{PostTicketTransition, TicketTransition};
+use crate::jira::tickets::{CommentBody, PostTicketTransition, TicketData};
+use crate::jira::users::User;
+use crate::widgets::attachments::{path_completions, AttachmentsWidget};
+use crate::widgets::autocomplete::AutocompleteWidget;
+use crate::widgets::bulk::{BulkOperation, BulkWidget};
 use crate::widgets::commands::CommandText;
 use crate::widgets::commands::{self, CommandInfo};
 use crate::widgets::comments::CommentsList;
-use crate::widgets::comments_add::CommentAdd;
+use crate::widgets::comments_add::{comment_markdown, CommentAdd};
 use crate::widgets::components::ComponentsWidget;
+use crate::widgets::confirm::ConfirmWidget;
 use crate::widgets::error::ErrorComponent;
+use crate::widgets::field_form::FieldForm;
+use crate::widgets::filters::FiltersWidget;
 use crate::widgets::help::HelpWidget;
+use crate::widgets::history::HistoryWidget;
+use crate::widgets::kanban::{CardMove, KanbanWidget};
 use crate::widgets::labels::LabelsWidget;
 use crate::widgets::parent::TicketParentWidget;
 use crate::widgets::search_projects::SearchProjectsWidget;
 use crate::widgets::search_tickets::SearchTicketsWidget;
+use crate::widgets::sprints::{sprint_date, SprintsWidget};
+use crate::widgets::subtask_add::SubtaskAdd;
+use crate::widgets::subtasks::SubtasksWidget;
+use crate::widgets::ticket_create::TicketCreateWidget;
+use crate::widgets::ticket_link::{NewLink, TicketLinkWidget};
 use crate::widgets::ticket_relation::RelationWidget;
 use crate::widgets::ticket_transition::TransitionWidget;
+use crate::widgets::ticket_tree::TicketTreeWidget;
 use crate::widgets::tickets::TicketWidget;
+use crate::widgets::user_picker::{UserPickerSelection, UserPickerWidget};
+use crate::widgets::watchers::WatchersWidget;
+use crate::widgets::worklog_add::WorklogAdd;
+use crate::widgets::worklogs::WorklogWidget;
 use crate::widgets::{DrawableComponent, InputMode};
 use crate::{
     config::Config,
@@ -21,8 +46,12 @@ use crate::{
     widgets::{Component, EventState},
 };
 use crate::{jira::Jira, widgets::projects::ProjectsWidget};
+use anyhow::anyhow;
+use html2md::parse_html;
+use serde_json::{json, Map, Value};
 use std::collections::HashMap;
-use log::debug;
+use std::env;
+use std::path::PathBuf;
 use tui::layout::Rect;
 use tui::{
     backend::Backend,
@@ -36,17 +65,46 @@ use tui::{
 // }
 
 pub enum Focus {
+    AttachmentUpload,
+    Attachments,
+    Bulk,
+    CommentDelete,
     CommentsAdd,
     CommentsList,
     Components,
+    Filters,
+    History,
+    JqlSearch,
+    Kanban,
+    LabelAdd,
     Labels,
     Projects,
     SearchProjects,
     SearchTickets,
+    Sprints,
+    SubtaskAdd,
+    Subtasks,
+    TicketAssign,
+    TicketCreate,
+    TicketLink,
     TicketParent,
     TicketRelation,
     TicketTransition,
+    TicketTree,
     Tickets,
+    WatcherAdd,
+    Watchers,
+    WorklogAdd,
+    Worklogs,
+}
+
+/// Where the tickets pane's list comes from, paging and resets stay within it.
+#[derive(Debug, Clone)]
+enum TicketSource {
+    Jql(String),
+    Project,
+    Sprint(Sprint),
+    Watched,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -104,6 +162,11 @@ impl ParentAction {
 
 #[derive(Debug, Clone, Copy)]
 enum TicketsAction {
+    AddComment,
+    AssignTicket,
+    OpenAttachments,
+    CreateTicket,
+    EditTicket,
     // FocusDescription,
     FocusRelation,
     FocusLabels,
@@ -112,15 +175,41 @@ enum TicketsAction {
     OpenHelp,
     OpenProjects,
     OpenTicketTransition,
+    OpenWatchers,
+    OpenWorklogs,
     PreviousPage,
     Reset,
     SearchTickets,
+    ToggleWatchedFilter,
+    JqlSearch,
+    OpenFilters,
+    OpenHistory,
+    ViewBoard,
+    ViewSprints,
+    ViewTree,
 }
 
 impl TicketsAction {
     fn to_command_text(self, key: Key) -> CommandText {
         const CMD_GROUP_GENERAL: &str = "-- Tickets Help --";
         match self {
+            Self::AddComment => CommandText::new(
+                format!("Add comment to ticket or marked tickets [{key}]"),
+                CMD_GROUP_GENERAL,
+            ),
+            Self::AssignTicket => {
+                CommandText::new(format!("Assign ticket [{key}]"), CMD_GROUP_GENERAL)
+            }
+            Self::OpenAttachments => {
+                CommandText::new(format!("Open Attachments View [{key}]"), CMD_GROUP_GENERAL)
+            }
+            Self::CreateTicket => {
+                CommandText::new(format!("Create new ticket [{key}]"), CMD_GROUP_GENERAL)
+            }
+            Self::EditTicket => CommandText::new(
+                format!("Edit summary and description in $EDITOR [{key}]"),
+                CMD_GROUP_GENERAL,
+            ),
             Self::FocusRelation => {
                 CommandText::new(format!("Focus on Relation pane [{key}]"), CMD_GROUP_GENERAL)
             }
@@ -131,6 +220,32 @@ impl TicketsAction {
             Self::OpenComments => {
                 CommandText::new(format!("Open Comments View [{key}]"), CMD_GROUP_GENERAL)
             }
+            Self::OpenWatchers => {
+                CommandText::new(format!("Open Watchers View [{key}]"), CMD_GROUP_GENERAL)
+            }
+            Self::ViewBoard => CommandText::new(format!("Board view [{key}]"), CMD_GROUP_GENERAL),
+            Self::OpenFilters => {
+                CommandText::new(format!("Favourite filters [{key}]"), CMD_GROUP_GENERAL)
+            }
+            Self::JqlSearch => {
+                CommandText::new(format!("Run a JQL query [{key}]"), CMD_GROUP_GENERAL)
+            }
+            Self::OpenHistory => {
+                CommandText::new(format!("Ticket history [{key}]"), CMD_GROUP_GENERAL)
+            }
+            Self::ViewTree => {
+                CommandText::new(format!("Parent/child tree [{key}]"), CMD_GROUP_GENERAL)
+            }
+            Self::ViewSprints => {
+                CommandText::new(format!("Boards and sprints [{key}]"), CMD_GROUP_GENERAL)
+            }
+            Self::ToggleWatchedFilter => CommandText::new(
+                format!("Only show watched tickets, from cache [{key}]"),
+                CMD_GROUP_GENERAL,
+            ),
+            Self::OpenWorklogs => {
+                CommandText::new(format!("Open Worklogs View [{key}]"), CMD_GROUP_GENERAL)
+            }
             Self::SearchTickets => CommandText::new(format!("Filter [{key}]"), CMD_GROUP_GENERAL),
             Self::NextPage => CommandText::new(format!("Next page [{key}]"), CMD_GROUP_GENERAL),
             Self::PreviousPage => {
@@ -155,6 +270,8 @@ impl TicketsAction {
 enum CommentsAction {
     OpenHelp,
     AddComment,
+    DeleteComment,
+    EditComment,
     FocusTickets,
 }
 
@@ -166,6 +283,12 @@ impl CommentsAction {
             Self::AddComment => {
                 CommandText::new(format!("Add comment to ticket [{key}]"), CMD_GROUP_GENERAL)
             }
+            Self::DeleteComment => {
+                CommandText::new(format!("Delete your comment [{key}]"), CMD_GROUP_GENERAL)
+            }
+            Self::EditComment => {
+                CommandText::new(format!("Edit your comment [{key}]"), CMD_GROUP_GENERAL)
+            }
             Self::FocusTickets => {
                 CommandText::new(format!("Go back to tickets [{key}]"), CMD_GROUP_GENERAL)
             }
@@ -175,35 +298,72 @@ impl CommentsAction {
 
 pub struct App {
     // load_state: LoadState,
+    attachment_upload: AutocompleteWidget,
+    attachments: AttachmentsWidget,
+    bulk: BulkWidget,
+    /// Marked tickets the widget opened from the tickets pane applies to, empty for one ticket.
+    bulk_keys: Vec<String>,
     comment_add: CommentAdd,
     comments_list: CommentsList,
     comments_key_mappings: HashMap<Key, CommentsAction>,
     components: ComponentsWidget,
+    confirm: ConfirmWidget,
     focus: Focus,
     help: HelpWidget,
+    history: HistoryWidget,
     jira: Jira,
+    label_add: AutocompleteWidget,
+    filters: FiltersWidget,
+    jql_prompt: AutocompleteWidget,
+    kanban: KanbanWidget,
     labels: LabelsWidget,
     parent: TicketParentWidget,
     parent_key_mappings: HashMap<Key, ParentAction>,
     projects: ProjectsWidget,
     projects_key_mappings: HashMap<Key, ProjectsAction>,
     relation: RelationWidget,
+    ticket_link: TicketLinkWidget,
     search_projects: SearchProjectsWidget,
     search_tickets: SearchTicketsWidget,
+    sprints: SprintsWidget,
+    subtask_add: SubtaskAdd,
+    subtasks: SubtasksWidget,
+    ticket_create: TicketCreateWidget,
     ticket_transition: TransitionWidget,
+    ticket_tree: TicketTreeWidget,
     tickets: TicketWidget,
     tickets_key_mappings: HashMap<Key, TicketsAction>,
+    ticket_source: TicketSource,
+    user_picker: UserPickerWidget,
+    watchers: WatchersWidget,
+    worklog_add: WorklogAdd,
+    worklogs: WorklogWidget,
     pub config: Config,
+    pub editor_request: Option<EditorRequest>,
     pub error: ErrorComponent,
 }
 
 impl App {
-    pub async fn new(config: Config) -> anyhow::Result<App> {
+    pub async fn new(config: Config, oauth: Option<OAuthSession>) -> anyhow::Result<App> {
+        let client = match oauth {
+            Some(oauth) => {
+                oauth_authentication(
+                    oauth,
+                    &config.jira_config.api_version.clone().unwrap(),
+                    &config.jira_config.user_email,
+                )
+                .await
+            }
+            None => jira_authentication(
+                config.jira_config.deployment,
+                &config.jira_config.domain,
+                &config.jira_config.api_key.clone().unwrap(),
+                &config.jira_config.api_version.clone().unwrap(),
+                &config.jira_config.user_email,
+            ),
+        };
         let mut jira = Jira::new(
-            &config.jira_config.domain,
-            &config.jira_config.api_key.clone().unwrap(),
-            &config.jira_config.api_version.clone().unwrap(),
-            &config.jira_config.user_email,
+            client,
             &config.jira_config.db_file,
             &config.jira_config.projects,
             &config.jira_config.tickets,
@@ -212,6 +372,10 @@ impl App {
         let projects = &jira.get_jira_projects().await?;
 
         Ok(Self {
+            attachment_upload: AutocompleteWidget::new(),
+            attachments: AttachmentsWidget::new(config.key_config.clone()),
+            bulk: BulkWidget::new(config.key_config.clone()),
+            bulk_keys: Vec::new(),
             comments_list: CommentsList::new(config.key_config.clone()),
             comment_add: CommentAdd::new(),
             comments_key_mappings: {
@@ -221,15 +385,24 @@ impl App {
                     config.key_config.ticket_add_comments,
                     CommentsAction::AddComment,
                 );
+                map.insert(config.key_config.ticket_edit, CommentsAction::EditComment);
+                map.insert(config.key_config.remove, CommentsAction::DeleteComment);
                 map.insert(config.key_config.esc, CommentsAction::FocusTickets);
                 map
             },
             components: ComponentsWidget::new(config.key_config.clone()),
+            confirm: ConfirmWidget::new(),
             config: config.clone(),
+            editor_request: None,
             error: ErrorComponent::new(config.key_config.clone()),
             focus: Focus::Projects,
             help: HelpWidget::new(config.key_config.clone()),
+            history: HistoryWidget::new(config.key_config.clone()),
             jira,
+            label_add: AutocompleteWidget::new(),
+            filters: FiltersWidget::new(config.key_config.clone()),
+            jql_prompt: AutocompleteWidget::new(),
+            kanban: KanbanWidget::new(config.key_config.clone()),
             labels: LabelsWidget::new(config.key_config.clone()),
             // load_state: LoadState::Complete,
             parent_key_mappings: {
@@ -257,8 +430,13 @@ impl App {
             },
 
             relation: RelationWidget::new(config.key_config.clone(), &config.jira_config.domain),
+            ticket_link: TicketLinkWidget::new(config.key_config.clone()),
             search_projects: SearchProjectsWidget::new(projects),
             search_tickets: SearchTicketsWidget::new(),
+            sprints: SprintsWidget::new(config.key_config.clone()),
+            subtask_add: SubtaskAdd::new(),
+            subtasks: SubtasksWidget::new(config.key_config.clone(), &config.jira_config.domain),
+            ticket_create: TicketCreateWidget::new(config.key_config.clone()),
             tickets: TicketWidget::new(
                 config.key_config.clone(),
                 config.jira_config.domain.clone(),
@@ -272,10 +450,39 @@ impl App {
                     TicketsAction::OpenComments,
                 );
                 map.insert(config.key_config.esc, TicketsAction::OpenProjects);
+                map.insert(
+                    config.key_config.ticket_add_comments,
+                    TicketsAction::AddComment,
+                );
+                map.insert(config.key_config.ticket_assign, TicketsAction::AssignTicket);
+                map.insert(config.key_config.ticket_create, TicketsAction::CreateTicket);
+                map.insert(config.key_config.ticket_edit, TicketsAction::EditTicket);
                 map.insert(
                     config.key_config.ticket_transition,
                     TicketsAction::OpenTicketTransition,
                 );
+                map.insert(
+                    config.key_config.ticket_view_attachments,
+                    TicketsAction::OpenAttachments,
+                );
+                map.insert(
+                    config.key_config.ticket_view_watchers,
+                    TicketsAction::OpenWatchers,
+                );
+                map.insert(
+                    config.key_config.ticket_view_worklogs,
+                    TicketsAction::OpenWorklogs,
+                );
+                map.insert(
+                    config.key_config.watched_filter,
+                    TicketsAction::ToggleWatchedFilter,
+                );
+                map.insert(config.key_config.sprints, TicketsAction::ViewSprints);
+                map.insert(config.key_config.board_view, TicketsAction::ViewBoard);
+                map.insert(config.key_config.ticket_tree, TicketsAction::ViewTree);
+                map.insert(config.key_config.jql_search, TicketsAction::JqlSearch);
+                map.insert(config.key_config.filters, TicketsAction::OpenFilters);
+                map.insert(config.key_config.ticket_history, TicketsAction::OpenHistory);
                 map.insert(config.key_config.open_help, TicketsAction::OpenHelp);
                 map.insert(config.key_config.filter, TicketsAction::SearchTickets);
                 map.insert(config.key_config.next_page, TicketsAction::NextPage);
@@ -284,6 +491,12 @@ impl App {
                 map
             },
             ticket_transition: TransitionWidget::new(Vec::new(), config.key_config.clone()),
+            ticket_tree: TicketTreeWidget::new(config.key_config.clone()),
+            ticket_source: TicketSource::Project,
+            user_picker: UserPickerWidget::new(),
+            watchers: WatchersWidget::new(config.key_config.clone()),
+            worklog_add: WorklogAdd::new(),
+            worklogs: WorklogWidget::new(config.key_config.clone()),
         })
     }
 
@@ -317,6 +530,7 @@ impl App {
                 matches!(self.focus, Focus::TicketTransition),
                 f.size(),
             )?;
+            self.error.draw(f, Rect::default(), false)?;
             return Ok(());
         }
 
@@ -325,6 +539,92 @@ impl App {
             return Ok(());
         }
 
+        if let Focus::TicketAssign = self.focus {
+            self.user_picker.draw(f)?;
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::TicketCreate = self.focus {
+            self.ticket_create.draw(f)?;
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::TicketLink = self.focus {
+            self.ticket_link.draw(f)?;
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::Attachments | Focus::AttachmentUpload = self.focus {
+            self.attachments
+                .draw(f, matches!(self.focus, Focus::Attachments))?;
+            if let Focus::AttachmentUpload = self.focus {
+                self.attachment_upload.draw(f)?;
+            }
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::Filters = self.focus {
+            self.filters.draw(f)?;
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::Bulk = self.focus {
+            self.bulk.draw(f)?;
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::History = self.focus {
+            self.history.draw(f)?;
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::TicketTree = self.focus {
+            self.ticket_tree.draw(f)?;
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::Kanban = self.focus {
+            self.kanban.draw(f)?;
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::Sprints = self.focus {
+            self.sprints.draw(f)?;
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::WatcherAdd = self.focus {
+            self.user_picker.draw(f)?;
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::Watchers = self.focus {
+            self.watchers.draw(f, true)?;
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
+        if let Focus::Worklogs | Focus::WorklogAdd = self.focus {
+            self.worklogs
+                .draw(f, matches!(self.focus, Focus::Worklogs))?;
+            if let Focus::WorklogAdd = self.focus {
+                self.worklog_add.draw(f)?;
+            }
+            self.error.draw(f, Rect::default(), false)?;
+            return Ok(());
+        }
+
         if let Focus::TicketTransition = self.focus {
             return Ok(());
         }
@@ -339,7 +639,13 @@ impl App {
             .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
             .split(main_chunks[0]);
 
-        let ticket_relation = main_chunks[1];
+        let bottom_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(main_chunks[1]);
+
+        let ticket_relation = bottom_chunks[0];
+        let ticket_subtasks = bottom_chunks[1];
 
         let ticket_left_chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -402,10 +708,33 @@ impl App {
             self.tickets.selected(),
         )?;
 
-        if let Focus::CommentsList = self.focus {
+        self.subtasks.draw(
+            f,
+            matches!(self.focus, Focus::Subtasks | Focus::SubtaskAdd),
+            ticket_subtasks,
+            self.tickets.selected(),
+        )?;
+
+        if let Focus::LabelAdd = self.focus {
+            self.label_add.draw(f)?;
+        }
+
+        if let Focus::JqlSearch = self.focus {
+            self.jql_prompt.draw(f)?;
+        }
+
+        if let Focus::SubtaskAdd = self.focus {
+            self.subtask_add.draw(f)?;
+        }
+
+        if let Focus::CommentsList | Focus::CommentDelete = self.focus {
             self.comments_list
                 .draw(f, matches!(self.focus, Focus::Projects), f.size())?;
+            if let Focus::CommentDelete = self.focus {
+                self.confirm.draw(f)?;
+            }
             self.help.draw(f, Rect::default(), false)?;
+            self.error.draw(f, Rect::default(), false)?;
             return Ok(());
         }
 
@@ -465,12 +794,21 @@ impl App {
     }
 
     pub async fn single_project_update(&mut self, project: Project) -> anyhow::Result<()> {
-        self.projects.update(&vec![project]).await
+        self.projects.update(&[project]).await
     }
 
     pub async fn next_ticket_page(&mut self) -> anyhow::Result<()> {
-        let project = self.projects.selected().unwrap();
-        self.jira.get_next_ticket_page(&project.key).await?;
+        match &self.ticket_source {
+            TicketSource::Jql(jql) => {
+                self.jira.get_next_jql_page(jql).await?;
+            }
+            TicketSource::Project => {
+                let project = self.projects.selected().unwrap();
+                self.jira.get_next_ticket_page(&project.key).await?;
+            }
+            // Sprints and watched tickets are listed in full
+            TicketSource::Sprint(_) | TicketSource::Watched => return Ok(()),
+        }
         self.tickets
             .update(self.jira.tickets.issues.clone(), true)
             .await?;
@@ -478,8 +816,16 @@ impl App {
     }
 
     pub async fn previous_ticket_page(&mut self) -> anyhow::Result<()> {
-        let project = self.projects.selected().unwrap();
-        self.jira.get_previous_tickets_page(&project.key).await?;
+        match &self.ticket_source {
+            TicketSource::Jql(jql) => {
+                self.jira.get_previous_jql_page(jql).await?;
+            }
+            TicketSource::Project => {
+                let project = self.projects.selected().unwrap();
+                self.jira.get_previous_tickets_page(&project.key).await?;
+            }
+            TicketSource::Sprint(_) | TicketSource::Watched => return Ok(()),
+        }
         self.tickets
             .update(self.jira.tickets.issues.clone(), true)
             .await?;
@@ -487,14 +833,60 @@ impl App {
     }
 
     pub async fn update_all_tickets(&mut self) -> anyhow::Result<()> {
+        if !matches!(self.ticket_source, TicketSource::Project) {
+            self.jira.tickets_start_at = 0;
+        }
         let project = self.projects.selected().unwrap();
         self.jira.get_jira_tickets(&project.key).await?;
+        self.ticket_source = TicketSource::Project;
+        self.tickets.title = None;
         self.tickets
             .update(self.jira.tickets.issues.clone(), true)
             .await?;
         Ok(())
     }
 
+    pub async fn open_jql_prompt(&mut self) -> anyhow::Result<()> {
+        let history = self.jira.get_jql_history().await?;
+        self.jql_prompt.open("JQL", history);
+        if let TicketSource::Jql(jql) = &self.ticket_source {
+            self.jql_prompt.set_input(jql);
+        }
+        self.focus = Focus::JqlSearch;
+        Ok(())
+    }
+
+    /// Lists the first page of `jql` in the tickets pane, the project's tickets stay on error.
+    pub async fn run_jql(&mut self, jql: String) -> anyhow::Result<()> {
+        let start_at = self.jira.tickets_start_at;
+        self.jira.tickets_start_at = 0;
+        if let Err(e) = self.jira.get_jql_tickets(&jql).await {
+            self.jira.tickets_start_at = start_at;
+            return Err(e);
+        }
+        self.tickets.title = Some(format!("JQL: {}", jql));
+        self.ticket_source = TicketSource::Jql(jql);
+        self.tickets
+            .update(self.jira.tickets.issues.clone(), true)
+            .await
+    }
+
+    pub async fn update_filters(&mut self, refresh: bool) -> anyhow::Result<()> {
+        let filters = self.jira.get_favourite_filters(refresh).await?;
+        self.filters.update(filters);
+        Ok(())
+    }
+
+    pub async fn update_history(&mut self, refresh: bool) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        let histories = self.jira.get_changelog(&ticket, refresh).await?;
+        self.history.update(&ticket.key, histories);
+        Ok(())
+    }
+
     pub async fn update_single_ticket(&mut self, ticket_key: &str) -> anyhow::Result<()> {
         let ticket = self.jira.search_cache_ticket(ticket_key).await?;
         self.tickets.remove_ticket(ticket_key)?;
@@ -520,6 +912,54 @@ impl App {
         Ok(())
     }
 
+    pub async fn open_label_add(&mut self) -> anyhow::Result<()> {
+        let ticket_labels = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.fields.labels.clone(),
+        };
+        self.bulk_keys = self.tickets.marked_keys();
+        let suggestions = self
+            .jira
+            .get_label_suggestions()
+            .await?
+            .into_iter()
+            .filter(|label| !ticket_labels.contains(label))
+            .collect();
+        let title = match self.bulk_keys.len() {
+            0 => "Add label".to_string(),
+            n => format!("Add label to {} marked tickets", n),
+        };
+        self.label_add.open(&title, suggestions);
+        self.focus = Focus::LabelAdd;
+        Ok(())
+    }
+
+    pub async fn add_label(&mut self, label: &str) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        ticket.add_label(label, &self.jira.client).await?;
+        self.jira.jira_ticket_api(&ticket.key).await?;
+        self.update_single_ticket(&ticket.key).await?;
+        self.update_labels().await
+    }
+
+    pub async fn remove_label(&mut self) -> anyhow::Result<()> {
+        let label = match self.labels.selected() {
+            None => return Ok(()),
+            Some(l) => l.clone(),
+        };
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        ticket.remove_label(&label, &self.jira.client).await?;
+        self.jira.jira_ticket_api(&ticket.key).await?;
+        self.update_single_ticket(&ticket.key).await?;
+        self.update_labels().await
+    }
+
     pub async fn update_components(&mut self) -> anyhow::Result<()> {
         let empty_vec = Vec::new();
         match self.tickets.selected() {
@@ -527,114 +967,1129 @@ impl App {
                 self.components.update(&empty_vec).await?;
             }
             Some(t) => {
+                let project_key = t.fields.project.key.clone();
+                if self.components.project_key.as_ref() != Some(&project_key) {
+                    let project_components = self.jira.get_project_components(&project_key).await?;
+                    self.components
+                        .update_project_components(&project_key, &project_components);
+                }
                 self.components.update(&t.fields.components).await?;
             }
         };
         Ok(())
     }
 
-    pub async fn update_comments_view(&mut self) -> anyhow::Result<()> {
-        let comments = match self.tickets.selected() {
+    pub async fn toggle_component(&mut self, component: &str) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
             None => return Ok(()),
-            Some(t) => t.get_comments(&self.jira.db, &self.jira.client).await?,
+            Some(t) => t.clone(),
+        };
+        if self.components.has_component(component) {
+            ticket
+                .remove_component(component, &self.jira.client)
+                .await?;
+        } else {
+            ticket.add_component(component, &self.jira.client).await?;
+        }
+        self.jira.jira_ticket_api(&ticket.key).await?;
+        self.update_single_ticket(&ticket.key).await?;
+        self.update_components().await
+    }
+
+    pub async fn open_ticket_link(&mut self) -> anyhow::Result<()> {
+        let ticket_key = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.key.clone(),
         };
-        self.comments_list.comments = Some(comments);
+        let link_types = self.jira.get_link_types().await?;
+        let ticket_keys = self
+            .jira
+            .get_cached_ticket_keys()
+            .await?
+            .into_iter()
+            .filter(|candidate| candidate.split_whitespace().next() != Some(ticket_key.as_str()))
+            .collect();
+        self.ticket_link.open(link_types, ticket_keys);
+        self.focus = Focus::TicketLink;
         Ok(())
     }
 
-    pub async fn add_comment(&mut self, comments: &Vec<String>) -> anyhow::Result<()> {
+    pub async fn link_ticket(&mut self, link: NewLink) -> anyhow::Result<()> {
         let ticket = match self.tickets.selected() {
             None => return Ok(()),
-            Some(t) => t,
+            Some(t) => t.clone(),
         };
-        let comment = comments.join(" \n ");
         ticket
-            .add_comment(&self.jira.db, &comment, &self.jira.client)
+            .link(
+                &link.link_type,
+                link.outward,
+                &link.target,
+                &self.jira.client,
+            )
             .await?;
-        Ok(())
+        self.jira.jira_ticket_api(&ticket.key).await?;
+        self.update_single_ticket(&ticket.key).await
     }
 
-    pub async fn update_ticket_transitions(&mut self) -> anyhow::Result<()> {
+    pub async fn unlink_ticket(&mut self) -> anyhow::Result<()> {
+        let link = match self.relation.selected() {
+            None => return Ok(()),
+            Some(l) => l.clone(),
+        };
         let ticket = match self.tickets.selected() {
             None => return Ok(()),
-            Some(t) => t,
+            Some(t) => t.clone(),
         };
+        ticket.unlink(&link, &self.jira.client).await?;
+        self.jira.jira_ticket_api(&ticket.key).await?;
+        self.update_single_ticket(&ticket.key).await
+    }
 
-        let transitions = ticket.get_transitions(&self.jira.client).await?;
-        self.ticket_transition.update(&transitions);
-        Ok(())
+    pub fn update_attachments_view(&mut self) {
+        if let Some(ticket) = self.tickets.selected() {
+            self.attachments.update(ticket);
+        }
     }
 
-    pub async fn move_ticket(&mut self) -> anyhow::Result<()> {
+    fn attachments_dir(&self) -> PathBuf {
+        match &self.config.jira_config.attachments_dir {
+            Some(dir) => expand_home(dir),
+            None => env::temp_dir().join("jirust"),
+        }
+    }
+
+    pub async fn download_attachment(&mut self, open: bool) -> anyhow::Result<()> {
+        let attachment = match self.attachments.selected() {
+            None => return Ok(()),
+            Some(a) => a.clone(),
+        };
         let ticket = match self.tickets.selected() {
-            Some(t) => t,
             None => return Ok(()),
+            Some(t) => t.clone(),
         };
-        let transition = self.ticket_transition.selected_transition().unwrap();
-        let data = PostTicketTransition {
-            transition: TicketTransition {
-                fields: None,
-                has_screen: None,
-                id: transition.id.clone(),
-                name: transition.name.clone(),
-            },
+        let path = ticket
+            .download_attachment(&attachment, &self.attachments_dir(), &self.jira.client)
+            .await?;
+        if open {
+            open::that(&path)?;
+        }
+        Ok(())
+    }
+
+    pub fn open_attachment_upload(&mut self) {
+        let input = "~/";
+        self.attachment_upload
+            .open_prefix("Upload file", input, path_completions(input));
+        self.focus = Focus::AttachmentUpload;
+    }
+
+    pub async fn upload_attachment(&mut self, input: &str) -> anyhow::Result<()> {
+        let path = expand_home(input);
+        if path.is_dir() {
+            let input = if input.ends_with('/') {
+                input.to_string()
+            } else {
+                format!("{}/", input)
+            };
+            self.attachment_upload.set_input(&input);
+            self.attachment_upload.update(path_completions(&input));
+            return Ok(());
+        }
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
         };
-        ticket.transition_ticket(data, &self.jira.client).await?;
+        self.focus = Focus::Attachments;
+        ticket.upload_attachment(&path, &self.jira.client).await?;
         self.jira.jira_ticket_api(&ticket.key).await?;
+        self.update_single_ticket(&ticket.key).await?;
+        self.update_attachments_view();
         Ok(())
     }
 
-    pub async fn widget_event(&mut self, key: Key) -> anyhow::Result<EventState> {
-        if self.error.event(key)?.is_consumed() {
-            return Ok(EventState::Consumed);
+    /// Opens the tree at the top-most ancestor of the selected ticket.
+    pub async fn open_ticket_tree(&mut self) -> anyhow::Result<()> {
+        let mut root = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        while let Some(parent) = &root.fields.parent {
+            let parent_key = parent.key.clone();
+            root = self.jira.search_cache_ticket(&parent_key).await?;
         }
+        let children = self.jira.get_child_tickets(&root.key).await?;
+        let root_key = root.key.clone();
+        self.ticket_tree.open(root);
+        self.ticket_tree.set_children(&root_key, children);
+        self.focus = Focus::TicketTree;
+        Ok(())
+    }
 
-        match self.focus {
-            Focus::CommentsList => {
-                if self.help.event(key)?.is_consumed() {
-                    self.update_comments_list_commands();
-                    return Ok(EventState::Consumed);
-                }
-                if self.comments_list.event(key)?.is_consumed() {
-                    return Ok(EventState::Consumed);
-                }
-            }
-            Focus::CommentsAdd => {
-                if self.comment_add.event(key)?.is_consumed() {
-                    if self.comment_add.push_comment {
-                        let comments = &self.comment_add.messages.clone();
-                        self.add_comment(comments).await?;
-                        self.comment_add.messages.clear();
-                        self.comment_add.push_comment = false;
-                    }
-                    return Ok(EventState::Consumed);
-                }
-            }
-            Focus::Components => {
-                if self.components.event(key)?.is_consumed() {
-                    return Ok(EventState::Consumed);
-                }
-            }
-            Focus::Labels => {
-                if self.labels.event(key)?.is_consumed() {
-                    return Ok(EventState::Consumed);
-                }
-            }
-            Focus::Projects => {
-                if self.help.event(key)?.is_consumed() {
-                    return Ok(EventState::Consumed);
-                }
-                if self.projects.event(key)?.is_consumed() {
-                    return Ok(EventState::Consumed);
-                }
+    pub fn open_subtask_add(&mut self) {
+        if let Some(ticket) = self.tickets.selected() {
+            self.subtask_add.open(&ticket.key);
+            self.focus = Focus::SubtaskAdd;
+        }
+    }
+
+    pub async fn create_subtasks(&mut self) -> anyhow::Result<()> {
+        let parent = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        let summaries = std::mem::take(&mut self.subtask_add.summaries);
+        let (created, failures) = self.jira.create_subtasks(&parent, &summaries).await?;
+        let created_count = created.len();
+        self.tickets.update(created, false).await?;
+        self.update_single_ticket(&parent.key).await?;
+        if !failures.is_empty() {
+            return Err(anyhow!(
+                "Created {} of {} subtasks. Failed: {}",
+                created_count,
+                summaries.len(),
+                failures.join("; ")
+            ));
+        }
+        Ok(())
+    }
+
+    pub async fn open_kanban(&mut self) -> anyhow::Result<()> {
+        let project = match self.projects.selected() {
+            None => return Ok(()),
+            Some(p) => p.key.clone(),
+        };
+        let board = match self.sprints.selected_board() {
+            Some(b) if b.project_key.as_deref() == Some(project.as_str()) => Some(b.clone()),
+            _ => match self.jira.get_boards(&project).await {
+                Ok(boards) => boards.into_iter().next(),
+                // Without Jira Software there are no boards, the columns are then the statuses.
+                Err(e) if is_agile_unavailable(&e) => None,
+                Err(e) => return Err(e),
+            },
+        };
+        let (title, columns) = match board {
+            Some(board) => {
+                let columns = self.jira.get_board_columns(&board).await?;
+                (board.name, Some(columns))
             }
-            Focus::SearchProjects => {
-                if self.search_projects.event(key)?.is_consumed() {
-                    return Ok(EventState::Consumed);
+            None => (format!("{} by status", project), None),
+        };
+        self.kanban.update(&title, columns, &self.tickets.tickets);
+        if let Some(ticket) = self.tickets.selected() {
+            let key = ticket.key.clone();
+            self.kanban.select_ticket(&key);
+        }
+        self.focus = Focus::Kanban;
+        Ok(())
+    }
+
+    /// Runs the transition that takes the card into the target column.
+    pub async fn move_kanban_card(&mut self, card: CardMove) -> anyhow::Result<()> {
+        let transitions = card.ticket.get_transitions(&self.jira.client).await?;
+        let transition = transitions
+            .transitions
+            .into_iter()
+            .find(|t| t.to.as_ref().is_some_and(|to| card.statuses.accepts(to)))
+            .ok_or_else(|| {
+                anyhow!(
+                    "No transition moves {} from {} to {}",
+                    card.ticket.key,
+                    card.ticket.fields.status.name,
+                    card.column
+                )
+            })?;
+        card.ticket
+            .transition_ticket(PostTicketTransition::new(&transition.id), &self.jira.client)
+            .await?;
+        self.jira.jira_ticket_api(&card.ticket.key).await?;
+        self.update_single_ticket(&card.ticket.key).await?;
+        self.kanban.refresh(&self.tickets.tickets);
+        self.kanban.select_ticket(&card.ticket.key);
+        Ok(())
+    }
+
+    pub async fn open_sprints(&mut self) -> anyhow::Result<()> {
+        let project = match self.projects.selected() {
+            None => return Ok(()),
+            Some(p) => p.key.clone(),
+        };
+        let boards = self.jira.get_boards(&project).await?;
+        self.sprints.update_boards(boards);
+        self.focus = Focus::Sprints;
+        Ok(())
+    }
+
+    pub async fn update_sprints(&mut self) -> anyhow::Result<()> {
+        let board = match self.sprints.selected_board() {
+            None => return Ok(()),
+            Some(b) => b.clone(),
+        };
+        let sprints = self.jira.get_sprints(&board).await?;
+        self.sprints.update_sprints(sprints);
+        Ok(())
+    }
+
+    pub async fn show_sprint(&mut self, sprint: Sprint) -> anyhow::Result<()> {
+        let tickets = self.jira.get_sprint_tickets(&sprint).await?;
+        let mut title = format!(
+            "{} ({}) {} - {}",
+            sprint.name,
+            sprint.state,
+            sprint_date(&sprint.start_date),
+            sprint_date(&sprint.end_date)
+        );
+        if let Some(goal) = sprint.goal.as_deref().filter(|g| !g.is_empty()) {
+            title = format!("{}: {}", title, goal);
+        }
+        self.tickets.title = Some(title);
+        self.ticket_source = TicketSource::Sprint(sprint);
+        self.tickets.update(tickets, true).await?;
+        self.focus = Focus::Tickets;
+        Ok(())
+    }
+
+    pub async fn toggle_watched_filter(&mut self) -> anyhow::Result<()> {
+        if let TicketSource::Watched = self.ticket_source {
+            return self.update_all_tickets().await;
+        }
+        let tickets = self.jira.get_watched_tickets().await?;
+        self.tickets.title = Some("Watched tickets".to_string());
+        self.ticket_source = TicketSource::Watched;
+        self.tickets.update(tickets, true).await
+    }
+
+    pub async fn update_watchers_view(&mut self) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        let watchers = ticket.get_watchers(&self.jira.client).await?;
+        self.watchers.update(&ticket.key, watchers);
+        Ok(())
+    }
+
+    async fn refresh_watchers(&mut self, ticket_key: &str) -> anyhow::Result<()> {
+        self.jira.jira_ticket_api(ticket_key).await?;
+        self.update_single_ticket(ticket_key).await?;
+        self.update_watchers_view().await
+    }
+
+    pub async fn toggle_watch(&mut self) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        let account_id = User::myself(&self.jira.client)
+            .await?
+            .account_id
+            .ok_or_else(|| anyhow!("Unable to find your account id"))?;
+        if self.watchers.is_watching() {
+            ticket
+                .remove_watcher(&account_id, &self.jira.client)
+                .await?;
+        } else {
+            ticket.add_watcher(&account_id, &self.jira.client).await?;
+        }
+        self.refresh_watchers(&ticket.key).await
+    }
+
+    pub async fn open_watcher_add(&mut self) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t,
+        };
+        let users = ticket.get_viewing_users("", &self.jira.client).await?;
+        self.user_picker
+            .open(&format!("Add watcher to {}", ticket.key), users, None);
+        self.focus = Focus::WatcherAdd;
+        Ok(())
+    }
+
+    pub async fn search_viewing_users(&mut self) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t,
+        };
+        let users = ticket
+            .get_viewing_users(&self.user_picker.input, &self.jira.client)
+            .await?;
+        self.user_picker.update(users);
+        Ok(())
+    }
+
+    /// Work waiting for the user to stop typing, run when no key came in for a tick.
+    pub async fn tick(&mut self) -> anyhow::Result<()> {
+        if matches!(self.focus, Focus::CommentsAdd) {
+            if let Some(query) = self.comment_add.settled_mention_query() {
+                self.search_mentions(&query).await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn search_mentions(&mut self, query: &str) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t,
+        };
+        let users = ticket.get_viewing_users(query, &self.jira.client).await?;
+        self.comment_add.update_mentions(users);
+        Ok(())
+    }
+
+    pub async fn add_watcher(&mut self, selection: UserPickerSelection) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        let account_id = match selection {
+            UserPickerSelection::Myself => User::myself(&self.jira.client).await?.account_id,
+            UserPickerSelection::Nobody => None,
+            UserPickerSelection::User(user) => user.account_id,
+        };
+        if let Some(account_id) = account_id {
+            ticket.add_watcher(&account_id, &self.jira.client).await?;
+            self.refresh_watchers(&ticket.key).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn remove_watcher(&mut self) -> anyhow::Result<()> {
+        let account_id = match self.watchers.selected() {
+            Some(User {
+                account_id: Some(id),
+                ..
+            }) => id.clone(),
+            _ => return Ok(()),
+        };
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        ticket
+            .remove_watcher(&account_id, &self.jira.client)
+            .await?;
+        self.refresh_watchers(&ticket.key).await
+    }
+
+    pub async fn update_worklogs_view(&mut self) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        let worklogs = ticket.get_worklogs(&self.jira.client).await?;
+        self.worklogs.update(&ticket.key, worklogs);
+        Ok(())
+    }
+
+    pub async fn add_worklog(&mut self) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        ticket
+            .add_worklog(
+                self.worklog_add.time_spent.trim(),
+                &self.worklog_add.comment,
+                &self.jira.client,
+            )
+            .await?;
+        self.jira.jira_ticket_api(&ticket.key).await?;
+        self.update_single_ticket(&ticket.key).await?;
+        self.update_worklogs_view().await
+    }
+
+    pub async fn update_comments_view(&mut self) -> anyhow::Result<()> {
+        let comments = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.get_comments(&self.jira.db, &self.jira.client).await?,
+        };
+        self.comments_list.update(comments).await?;
+        Ok(())
+    }
+
+    /// The selected comment, when the current user wrote it.
+    async fn own_selected_comment(&self) -> anyhow::Result<Option<CommentBody>> {
+        let comment = match self.comments_list.selected() {
+            None => return Ok(None),
+            Some(c) => c.clone(),
+        };
+        let myself = User::myself(&self.jira.client).await?;
+        let own = match (&comment.author.account_id, &myself.account_id) {
+            (Some(author), Some(me)) => author == me,
+            _ => false,
+        };
+        if comment.id.is_none() || !own {
+            return Err(anyhow!("You can only change your own comments"));
+        }
+        Ok(Some(comment))
+    }
+
+    pub async fn open_comment_edit(&mut self) -> anyhow::Result<()> {
+        if let Some(comment) = self.own_selected_comment().await? {
+            // Going through ADF keeps mentions, which the rendered html only has as links
+            let body = match &comment.body {
+                Some(adf) if adf.is_object() => adf_to_markdown(adf)?,
+                Some(Value::String(wiki)) => wiki.clone(),
+                _ => parse_html(&comment.rendered_body),
+            };
+            self.comment_add
+                .edit(comment.id.as_deref().unwrap_or_default(), &body);
+            self.focus = Focus::CommentsAdd;
+        }
+        Ok(())
+    }
+
+    pub async fn edit_comment(&mut self, comment_id: &str, lines: &[String]) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t,
+        };
+        let comment = comment_markdown(lines);
+        ticket
+            .update_comment(comment_id, &comment, &self.jira.client)
+            .await?;
+        self.update_comments_view().await
+    }
+
+    pub async fn open_comment_delete(&mut self) -> anyhow::Result<()> {
+        if let Some(comment) = self.own_selected_comment().await? {
+            self.confirm.open(&format!(
+                "Delete your comment from {}?",
+                comment.created.get(..10).unwrap_or(&comment.created)
+            ));
+            self.focus = Focus::CommentDelete;
+        }
+        Ok(())
+    }
+
+    pub async fn delete_comment(&mut self) -> anyhow::Result<()> {
+        let (ticket, comment_id) = match (self.tickets.selected(), self.comments_list.selected()) {
+            (Some(t), Some(c)) => (t, c.id.clone().unwrap_or_default()),
+            _ => return Ok(()),
+        };
+        ticket
+            .delete_comment(&comment_id, &self.jira.client)
+            .await?;
+        self.update_comments_view().await
+    }
+
+    pub async fn add_comment(&mut self, comments: &[String]) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t,
+        };
+        let comment = comment_markdown(comments);
+        ticket
+            .add_comment(&self.jira.db, &comment, &self.jira.client)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn open_assignable_users(&mut self) -> anyhow::Result<()> {
+        self.bulk_keys = self.tickets.marked_keys();
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t,
+        };
+        let users = ticket.get_assignable_users("", &self.jira.client).await?;
+        let title = match self.bulk_keys.len() {
+            0 => format!("Assign {}", ticket.key),
+            n => format!("Assign {} marked tickets", n),
+        };
+        self.user_picker.open(&title, users, Some("unassign"));
+        Ok(())
+    }
+
+    pub async fn search_assignable_users(&mut self) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t,
+        };
+        let users = ticket
+            .get_assignable_users(&self.user_picker.input, &self.jira.client)
+            .await?;
+        self.user_picker.update(users);
+        Ok(())
+    }
+
+    pub async fn assign_ticket(&mut self, selection: UserPickerSelection) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t.clone(),
+        };
+        let account_id = match selection {
+            UserPickerSelection::Myself => User::myself(&self.jira.client).await?.account_id,
+            UserPickerSelection::Nobody => None,
+            UserPickerSelection::User(user) => user.account_id,
+        };
+        ticket
+            .assign(account_id.as_deref(), &self.jira.client)
+            .await?;
+        self.jira.jira_ticket_api(&ticket.key).await?;
+        self.update_single_ticket(&ticket.key).await
+    }
+
+    pub async fn bulk_assign(&mut self, selection: UserPickerSelection) -> anyhow::Result<()> {
+        let (account_id, name) = match selection {
+            UserPickerSelection::Myself => {
+                let myself = User::myself(&self.jira.client).await?;
+                (myself.account_id, myself.display_name)
+            }
+            UserPickerSelection::Nobody => (None, "nobody".to_string()),
+            UserPickerSelection::User(user) => (user.account_id, user.display_name),
+        };
+        self.start_bulk(BulkOperation::Assign { account_id, name });
+        Ok(())
+    }
+
+    /// Queues `operation` for the marked tickets, `bulk_step` then applies it one ticket at a time.
+    fn start_bulk(&mut self, operation: BulkOperation) {
+        let keys = std::mem::take(&mut self.bulk_keys);
+        self.bulk.start(operation, keys);
+        self.focus = Focus::Bulk;
+    }
+
+    pub fn bulk_running(&self) -> bool {
+        self.bulk.is_running()
+    }
+
+    /// Applies the bulk operation to the next ticket, a failure is recorded and the run goes on.
+    pub async fn bulk_step(&mut self) -> anyhow::Result<()> {
+        let (key, operation) = match (self.bulk.next_pending(), &self.bulk.operation) {
+            (Some(key), Some(operation)) => (key.to_string(), operation.clone()),
+            _ => return Ok(()),
+        };
+        let outcome = self.apply_bulk_operation(&key, operation).await;
+        self.bulk.record(&key, outcome);
+        if !self.bulk.is_running() {
+            self.tickets.clear_marks();
+        }
+        Ok(())
+    }
+
+    async fn apply_bulk_operation(
+        &mut self,
+        key: &str,
+        operation: BulkOperation,
+    ) -> anyhow::Result<()> {
+        let ticket = self
+            .tickets
+            .tickets
+            .iter()
+            .find(|t| t.key == key)
+            .cloned()
+            .ok_or_else(|| anyhow!("{} is no longer listed", key))?;
+        match operation {
+            BulkOperation::Assign { account_id, .. } => {
+                ticket
+                    .assign(account_id.as_deref(), &self.jira.client)
+                    .await?
+            }
+            BulkOperation::Comment(comment) => {
+                ticket
+                    .add_comment(&self.jira.db, &comment, &self.jira.client)
+                    .await?;
+            }
+            BulkOperation::Label(label) => ticket.add_label(&label, &self.jira.client).await?,
+            BulkOperation::Transition { name, values } => {
+                let transition = ticket
+                    .get_transitions(&self.jira.client)
+                    .await?
+                    .transitions
+                    .into_iter()
+                    .find(|t| t.name.as_deref() == Some(name.as_str()))
+                    .ok_or_else(|| {
+                        anyhow!("No {} transition from {}", name, ticket.fields.status.name)
+                    })?;
+                let data = PostTicketTransition::new(&transition.id).with_values(values);
+                ticket.transition_ticket(data, &self.jira.client).await?;
+            }
+        }
+        self.jira.jira_ticket_api(key).await?;
+        self.update_single_ticket(key).await
+    }
+
+    pub async fn update_ticket_create_types(&mut self) -> anyhow::Result<()> {
+        let project = match self.projects.selected() {
+            None => return Ok(()),
+            Some(p) => p.key.clone(),
+        };
+        let issue_types = self.jira.get_create_issue_types(&project).await?;
+        self.ticket_create.update(issue_types.issue_types);
+        Ok(())
+    }
+
+    pub async fn update_ticket_create_fields(&mut self) -> anyhow::Result<()> {
+        let project = match self.projects.selected() {
+            None => return Ok(()),
+            Some(p) => p.key.clone(),
+        };
+        let issue_type = match self.ticket_create.selected_issue_type() {
+            None => return Ok(()),
+            Some(t) => t.id.clone(),
+        };
+        let fields = self.jira.get_create_fields(&project, &issue_type).await?;
+        self.ticket_create.update_fields(fields.fields);
+        Ok(())
+    }
+
+    pub async fn create_ticket(&mut self) -> anyhow::Result<()> {
+        let project = match self.projects.selected() {
+            None => return Ok(()),
+            Some(p) => p.key.clone(),
+        };
+        let issue_type = match self.ticket_create.selected_issue_type() {
+            None => return Ok(()),
+            Some(t) => t.id.clone(),
+        };
+        let mut fields = self.ticket_create.field_values(&self.jira.client)?;
+        self.resolve_user_inputs(&self.ticket_create.form, ("project", &project), &mut fields)
+            .await?;
+        fields.insert("project".to_string(), json!({ "key": project }));
+        fields.insert("issuetype".to_string(), json!({ "id": issue_type }));
+        let ticket = self.jira.create_ticket(fields).await?;
+        self.update_single_ticket(&ticket.key).await?;
+        self.ticket_create.clear();
+        self.focus = Focus::Tickets;
+        Ok(())
+    }
+
+    pub fn edit_ticket(&mut self) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t,
+        };
+        // Like comments, the description is edited from its source so nothing is lost on saving
+        let description = match &ticket.fields.description {
+            Some(adf) if adf.is_object() => adf_to_markdown(adf)?,
+            Some(Value::String(wiki)) => wiki.clone(),
+            _ => String::new(),
+        };
+        let contents = format!("{}\n\n{}", ticket.fields.summary, description);
+        self.editor_request = Some(EditorRequest::new(&ticket.key, contents)?);
+        Ok(())
+    }
+
+    /// Saves the edited ticket, the file is only removed once Jira has the changes.
+    pub async fn editor_closed(&mut self, request: EditorRequest) -> anyhow::Result<()> {
+        let edited = match request.read()? {
+            None => return request.remove(),
+            Some(e) => e,
+        };
+        if let Err(err) = self.save_edited_ticket(&request, &edited).await {
+            return Err(anyhow!(
+                "{}, your edits are kept in {}",
+                err,
+                request.path().display()
+            ));
+        }
+        request.remove()?;
+        self.jira.jira_ticket_api(&request.ticket_key).await?;
+        self.update_single_ticket(&request.ticket_key).await
+    }
+
+    async fn save_edited_ticket(
+        &self,
+        request: &EditorRequest,
+        edited: &str,
+    ) -> anyhow::Result<()> {
+        let (summary, description) = split_summary(edited);
+        // Saving an untouched description would still rewrite it
+        let description = match description == split_summary(request.contents()).1 {
+            true => None,
+            false => Some(description),
+        };
+        if summary.is_empty() {
+            return Err(anyhow!("Summary can not be empty"));
+        }
+        let ticket = match self
+            .tickets
+            .tickets
+            .iter()
+            .find(|t| t.key == request.ticket_key)
+        {
+            None => return Err(anyhow!("{} is no longer listed", request.ticket_key)),
+            Some(t) => t,
+        };
+        ticket
+            .update_summary_description(summary, description, &self.jira.client)
+            .await
+    }
+
+    pub async fn update_ticket_transitions(&mut self) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            None => return Ok(()),
+            Some(t) => t,
+        };
+
+        let transitions = ticket.get_transitions(&self.jira.client).await?;
+        self.ticket_transition.update(&transitions);
+        self.bulk_keys = self.tickets.marked_keys();
+        Ok(())
+    }
+
+    pub async fn move_ticket(&mut self) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            Some(t) => t.clone(),
+            None => return Ok(()),
+        };
+        let transition = match self.ticket_transition.selected_transition() {
+            Some(t) => t.clone(),
+            None => return Ok(()),
+        };
+        let values = self.transition_values(&ticket).await?;
+        let data = PostTicketTransition::new(&transition.id).with_values(values);
+        ticket.transition_ticket(data, &self.jira.client).await?;
+        self.jira.jira_ticket_api(&ticket.key).await?;
+        Ok(())
+    }
+
+    /// Runs the selected transition, with the same field values, on every marked ticket.
+    pub async fn bulk_transition(&mut self) -> anyhow::Result<()> {
+        let ticket = match self.tickets.selected() {
+            Some(t) => t.clone(),
+            None => return Ok(()),
+        };
+        let name = match self.ticket_transition.selected_transition() {
+            Some(t) => t.name.clone().unwrap_or_else(|| t.id.clone()),
+            None => return Ok(()),
+        };
+        let values = self.transition_values(&ticket).await?;
+        self.start_bulk(BulkOperation::Transition { name, values });
+        Ok(())
+    }
+
+    /// The transition form's values, with the typed user names looked up on `ticket`.
+    async fn transition_values(&self, ticket: &TicketData) -> anyhow::Result<Map<String, Value>> {
+        let form = &self.ticket_transition.form;
+        let missing = form.missing_required(&self.jira.client)?;
+        if !missing.is_empty() {
+            return Err(anyhow!(
+                "Fill in the required fields: {}",
+                missing.join(", ")
+            ));
+        }
+
+        let mut values = form.values(&self.jira.client)?;
+        self.resolve_user_inputs(form, ("issueKey", &ticket.key), &mut values)
+            .await?;
+        Ok(values)
+    }
+
+    /// Replaces the names typed in the form's user fields with references to the users
+    /// assignable in `scope`, see `User::assignable`.
+    async fn resolve_user_inputs(
+        &self,
+        form: &FieldForm,
+        scope: (&str, &str),
+        values: &mut Map<String, Value>,
+    ) -> anyhow::Result<()> {
+        for (field, input) in form.user_inputs() {
+            let mut users = Vec::new();
+            for name in input.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+                let account_id = self.find_assignable_user(scope, name).await?;
+                users.push(self.jira.client.user_ref(Some(&account_id)));
+            }
+            let value = if field.schema.field_type == "array" {
+                Value::Array(users)
+            } else if users.len() > 1 {
+                return Err(anyhow!("{} takes a single user", field.name));
+            } else {
+                users
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| anyhow!("No user given for {}", field.name))?
+            };
+            values.insert(field.id().to_string(), value);
+        }
+        Ok(())
+    }
+
+    /// Account id of the single user assignable in `scope` matching a typed name or email.
+    async fn find_assignable_user(
+        &self,
+        scope: (&str, &str),
+        name: &str,
+    ) -> anyhow::Result<String> {
+        let users = User::assignable(scope, name, &self.jira.client).await?;
+        let exact = users.iter().find(|u| {
+            u.display_name.eq_ignore_ascii_case(name)
+                || u.email_address
+                    .as_deref()
+                    .is_some_and(|e| e.eq_ignore_ascii_case(name))
+        });
+        let user = match (exact, users.as_slice()) {
+            (Some(user), _) => user,
+            (None, [user]) => user,
+            (None, []) => return Err(anyhow!("No assignable user matches {}", name)),
+            (None, _) => return Err(anyhow!("{} matches several users, be more precise", name)),
+        };
+        user.account_id
+            .clone()
+            .ok_or_else(|| anyhow!("{} has no account id", user.display_name))
+    }
+
+    pub async fn widget_event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if self.error.event(key)?.is_consumed() {
+            return Ok(EventState::Consumed);
+        }
+
+        match self.focus {
+            Focus::CommentsList => {
+                if self.help.event(key)?.is_consumed() {
+                    self.update_comments_list_commands();
+                    return Ok(EventState::Consumed);
+                }
+                if self.comments_list.event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::CommentDelete => {
+                if self.confirm.event(key)?.is_consumed() {
+                    if let Some(confirmed) = self.confirm.confirmed.take() {
+                        self.focus = Focus::CommentsList;
+                        if confirmed {
+                            self.delete_comment().await?;
+                        }
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::CommentsAdd => {
+                if self.comment_add.event(key)?.is_consumed() {
+                    if let Some(query) = self.comment_add.search_mentions.take() {
+                        self.search_mentions(&query).await?;
+                    }
+                    if self.comment_add.push_comment {
+                        let comments = &self.comment_add.messages.clone();
+                        match self.comment_add.editing.clone() {
+                            Some(comment_id) => {
+                                self.edit_comment(&comment_id, comments).await?;
+                                self.comment_add.clear();
+                                self.focus = Focus::CommentsList;
+                            }
+                            None if self.bulk_keys.is_empty() => self.add_comment(comments).await?,
+                            None => {
+                                self.comment_add.clear();
+                                self.start_bulk(BulkOperation::Comment(comment_markdown(comments)));
+                            }
+                        }
+                        self.comment_add.messages.clear();
+                        self.comment_add.push_comment = false;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Components => {
+                if self.components.event(key)?.is_consumed() {
+                    if let Some(component) = self.components.toggled.take() {
+                        self.toggle_component(&component).await?;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::LabelAdd => {
+                if self.label_add.event(key)?.is_consumed() {
+                    if let Some(label) = self.label_add.submitted.take() {
+                        self.focus = Focus::Labels;
+                        if self.bulk_keys.is_empty() {
+                            self.add_label(&label).await?;
+                        } else {
+                            self.start_bulk(BulkOperation::Label(label));
+                        }
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Labels => {
+                if self.labels.event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Projects => {
+                if self.help.event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed);
+                }
+                if self.projects.event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::SearchProjects => {
+                if self.search_projects.event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::SearchTickets => {
+                if self.search_tickets.event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::TicketAssign => {
+                if self.user_picker.event(key)?.is_consumed() {
+                    if self.user_picker.search_users {
+                        self.user_picker.search_users = false;
+                        self.search_assignable_users().await?;
+                    }
+                    if let Some(selection) = self.user_picker.selection.take() {
+                        self.focus = Focus::Tickets;
+                        if self.bulk_keys.is_empty() {
+                            self.assign_ticket(selection).await?;
+                        } else {
+                            self.bulk_assign(selection).await?;
+                        }
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::TicketCreate => {
+                if self.ticket_create.event(key)?.is_consumed() {
+                    if self.ticket_create.load_fields {
+                        self.ticket_create.load_fields = false;
+                        self.update_ticket_create_fields().await?;
+                    }
+                    if self.ticket_create.push_ticket {
+                        self.ticket_create.push_ticket = false;
+                        self.create_ticket().await?;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::AttachmentUpload => {
+                if self.attachment_upload.event(key)?.is_consumed() {
+                    if self.attachment_upload.input_changed {
+                        self.attachment_upload.input_changed = false;
+                        let completions = path_completions(&self.attachment_upload.input);
+                        self.attachment_upload.update(completions);
+                    }
+                    if let Some(input) = self.attachment_upload.submitted.take() {
+                        self.upload_attachment(&input).await?;
+                    }
+                    return Ok(EventState::Consumed);
                 }
             }
-            Focus::SearchTickets => {
-                if self.search_tickets.event(key)?.is_consumed() {
+            Focus::Attachments => {
+                if self.attachments.event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Bulk => {
+                if self.bulk.event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Filters => {
+                if self.filters.event(key)?.is_consumed() {
+                    if self.filters.reload_filters {
+                        self.filters.reload_filters = false;
+                        self.update_filters(true).await?;
+                    }
+                    if let Some(filter) = self.filters.chosen_filter.take() {
+                        self.run_jql(filter.jql).await?;
+                        self.tickets.title = Some(format!("Filter: {}", filter.name));
+                        self.focus = Focus::Tickets;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::History => {
+                if self.history.event(key)?.is_consumed() {
+                    if self.history.reload_history {
+                        self.history.reload_history = false;
+                        self.update_history(true).await?;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::JqlSearch => {
+                if self.jql_prompt.event(key)?.is_consumed() {
+                    if let Some(jql) = self.jql_prompt.submitted.take() {
+                        self.focus = Focus::Tickets;
+                        self.run_jql(jql).await?;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::TicketTree => {
+                if self.ticket_tree.event(key)?.is_consumed() {
+                    if let Some(parent_key) = self.ticket_tree.load_children.take() {
+                        let children = self.jira.get_child_tickets(&parent_key).await?;
+                        self.ticket_tree.set_children(&parent_key, children);
+                    }
+                    if let Some(parent_key) = self.ticket_tree.reload_children.take() {
+                        let children = self.jira.record_child_tickets(&parent_key).await?;
+                        self.ticket_tree.set_children(&parent_key, children);
+                    }
+                    if let Some(ticket_key) = self.ticket_tree.chosen_ticket.take() {
+                        self.update_single_ticket(&ticket_key).await?;
+                        self.focus = Focus::Tickets;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Kanban => {
+                if self.kanban.event(key)?.is_consumed() {
+                    if let Some(card) = self.kanban.move_card.take() {
+                        self.move_kanban_card(card).await?;
+                    }
+                    if let Some(ticket_key) = self.kanban.chosen_ticket.take() {
+                        self.tickets.select_ticket(&ticket_key)?;
+                        self.focus = Focus::Tickets;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Sprints => {
+                if self.sprints.event(key)?.is_consumed() {
+                    if self.sprints.load_sprints {
+                        self.sprints.load_sprints = false;
+                        self.update_sprints().await?;
+                    }
+                    if let Some(sprint) = self.sprints.chosen_sprint.take() {
+                        self.show_sprint(sprint).await?;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::WatcherAdd => {
+                if self.user_picker.event(key)?.is_consumed() {
+                    if self.user_picker.search_users {
+                        self.user_picker.search_users = false;
+                        self.search_viewing_users().await?;
+                    }
+                    if let Some(selection) = self.user_picker.selection.take() {
+                        self.focus = Focus::Watchers;
+                        self.add_watcher(selection).await?;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Watchers => {
+                if self.watchers.event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::WorklogAdd => {
+                if self.worklog_add.event(key)?.is_consumed() {
+                    if self.worklog_add.push_worklog {
+                        self.worklog_add.push_worklog = false;
+                        self.focus = Focus::Worklogs;
+                        self.add_worklog().await?;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Worklogs => {
+                if self.worklogs.event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::TicketLink => {
+                if self.ticket_link.event(key)?.is_consumed() {
+                    if let Some(link) = self.ticket_link.submitted.take() {
+                        self.focus = Focus::TicketRelation;
+                        self.link_ticket(link).await?;
+                    }
                     return Ok(EventState::Consumed);
                 }
             }
@@ -643,11 +2098,33 @@ impl App {
                     return Ok(EventState::Consumed);
                 }
             }
+            Focus::Subtasks => {
+                if self.subtasks.event(key)?.is_consumed() {
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::SubtaskAdd => {
+                if self.subtask_add.event(key)?.is_consumed() {
+                    if self.subtask_add.push_subtasks {
+                        self.subtask_add.push_subtasks = false;
+                        self.focus = Focus::Subtasks;
+                        self.create_subtasks().await?;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+            }
             Focus::Tickets => {
                 if key == self.config.key_config.reset {
                     self.tickets.tickets.clear();
                     self.jira.clear_tickets_table().await?;
-                    self.update_all_tickets().await?;
+                    match self.ticket_source.clone() {
+                        TicketSource::Jql(jql) => self.run_jql(jql).await?,
+                        TicketSource::Sprint(sprint) => self.show_sprint(sprint).await?,
+                        // The watched list is read from the cache that was just cleared
+                        TicketSource::Project | TicketSource::Watched => {
+                            self.update_all_tickets().await?
+                        }
+                    }
                     return Ok(EventState::Consumed);
                 }
 
@@ -669,20 +2146,18 @@ impl App {
             Focus::TicketTransition => {
                 if self.ticket_transition.event(key)?.is_consumed() {
                     if self.ticket_transition.push_transition {
-                        debug!("Transitioning {:?} to {:?}", self.tickets.selected(), self.ticket_transition.selected_transition());
-                        if !self.ticket_transition.comment_float_screen.is_empty() {
-                            debug!("Reason {:?}", self.ticket_transition.selected_transition_reason());
-                            self.add_comment(vec![self.ticket_transition.comment_float_screen.clone()].as_ref())
-                                .await?;
-                            self.ticket_transition.comment_float_screen.clear();
-                        }
-                        self.move_ticket().await?;
-                        if let Some(t) = self.tickets.selected() {
-                            let ticket = t.clone();
-                            self.update_single_ticket(&ticket.key).await?;
-                        }
                         self.ticket_transition.push_transition = false;
-                        self.focus = Focus::Tickets;
+                        if self.bulk_keys.is_empty() {
+                            self.move_ticket().await?;
+                            if let Some(t) = self.tickets.selected() {
+                                let ticket = t.clone();
+                                self.update_single_ticket(&ticket.key).await?;
+                            }
+                            self.focus = Focus::Tickets;
+                        } else {
+                            self.bulk_transition().await?;
+                        }
+                        self.ticket_transition.form.clear();
                     }
                     return Ok(EventState::Consumed);
                 }
@@ -694,6 +2169,12 @@ impl App {
 
     async fn move_focus(&mut self, key: Key) -> anyhow::Result<EventState> {
         match self.focus {
+            Focus::CommentDelete => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::CommentsList;
+                    return Ok(EventState::Consumed);
+                }
+            }
             Focus::CommentsList => {
                 if let Some(action) = self.comments_key_mappings.get(&key) {
                     log::debug!("got comments focus event: {key:?}");
@@ -717,9 +2198,21 @@ impl App {
                             self.help.show()?;
                         }
                         AddComment => {
+                            if self.comment_add.editing.is_some() {
+                                self.comment_add.clear();
+                            }
+                            self.bulk_keys.clear();
                             self.focus = Focus::CommentsAdd;
                             return Ok(EventState::Consumed);
                         }
+                        EditComment => {
+                            self.open_comment_edit().await?;
+                            return Ok(EventState::Consumed);
+                        }
+                        DeleteComment => {
+                            self.open_comment_delete().await?;
+                            return Ok(EventState::Consumed);
+                        }
                         FocusTickets => {
                             self.focus = Focus::Tickets;
                             return Ok(EventState::Consumed);
@@ -755,7 +2248,21 @@ impl App {
                     return Ok(EventState::Consumed);
                 }
             }
+            Focus::LabelAdd => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Labels;
+                    return Ok(EventState::Consumed);
+                }
+            }
             Focus::Labels => {
+                if key == self.config.key_config.add {
+                    self.open_label_add().await?;
+                    return Ok(EventState::Consumed);
+                }
+                if key == self.config.key_config.remove {
+                    self.remove_label().await?;
+                    return Ok(EventState::Consumed);
+                }
                 if key == self.config.key_config.previous || key == self.config.key_config.move_up {
                     self.focus = Focus::Tickets;
                     return Ok(EventState::Consumed);
@@ -895,12 +2402,175 @@ impl App {
                     return Ok(EventState::Consumed);
                 }
             }
+            Focus::TicketAssign => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::TicketCreate => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::TicketLink => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::TicketRelation;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::SubtaskAdd => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Subtasks;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Subtasks => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+
+                if key == self.config.key_config.add {
+                    self.open_subtask_add();
+                    return Ok(EventState::Consumed);
+                }
+
+                if key == self.config.key_config.enter {
+                    if let Some(subtask) = self.subtasks.selected() {
+                        let subtask_key = subtask.key.clone();
+                        self.update_single_ticket(&subtask_key).await?;
+                        self.focus = Focus::Tickets;
+                    }
+                    return Ok(EventState::Consumed);
+                }
+
+                if key == self.config.key_config.previous || key == self.config.key_config.move_left
+                {
+                    self.focus = Focus::TicketRelation;
+                    return Ok(EventState::Consumed);
+                }
+
+                if key == self.config.key_config.next || key == self.config.key_config.move_right {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::AttachmentUpload => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Attachments;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Bulk => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Attachments => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+                if key == self.config.key_config.enter {
+                    self.download_attachment(false).await?;
+                    return Ok(EventState::Consumed);
+                }
+                if key == self.config.key_config.open_browser {
+                    self.download_attachment(true).await?;
+                    return Ok(EventState::Consumed);
+                }
+                if key == self.config.key_config.add {
+                    self.open_attachment_upload();
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Filters => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::History => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::JqlSearch => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::TicketTree => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Kanban => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Sprints => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::WatcherAdd => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Watchers;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Watchers => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+                if key == self.config.key_config.ticket_watch {
+                    self.toggle_watch().await?;
+                    return Ok(EventState::Consumed);
+                }
+                if key == self.config.key_config.add {
+                    self.open_watcher_add().await?;
+                    return Ok(EventState::Consumed);
+                }
+                if key == self.config.key_config.remove {
+                    self.remove_watcher().await?;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::WorklogAdd => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Worklogs;
+                    return Ok(EventState::Consumed);
+                }
+            }
+            Focus::Worklogs => {
+                if key == self.config.key_config.esc {
+                    self.focus = Focus::Tickets;
+                    return Ok(EventState::Consumed);
+                }
+                if key == self.config.key_config.add {
+                    self.worklog_add.clear();
+                    self.focus = Focus::WorklogAdd;
+                    return Ok(EventState::Consumed);
+                }
+            }
             Focus::TicketParent => {
                 if let Some(action) = self.parent_key_mappings.get(&key) {
                     log::debug!("got tickets focus event: {key:?}");
                     use ParentAction::*;
                     match *action {
                         FocusComponent => {
+                            self.update_components().await?;
                             self.focus = Focus::Components;
                             return Ok(EventState::Consumed);
                         }
@@ -927,6 +2597,7 @@ impl App {
                     }
                 }
                 if key == self.config.key_config.previous || key == self.config.key_config.move_up {
+                    self.update_components().await?;
                     self.focus = Focus::Components;
                     return Ok(EventState::Consumed);
                 }
@@ -941,14 +2612,23 @@ impl App {
                     return Ok(EventState::Consumed);
                 }
 
+                if key == self.config.key_config.add {
+                    self.open_ticket_link().await?;
+                    return Ok(EventState::Consumed);
+                }
+
+                if key == self.config.key_config.remove {
+                    self.unlink_ticket().await?;
+                    return Ok(EventState::Consumed);
+                }
+
                 if key == self.config.key_config.previous || key == self.config.key_config.move_up {
                     self.focus = Focus::TicketParent;
                     return Ok(EventState::Consumed);
                 }
 
                 if key == self.config.key_config.next || key == self.config.key_config.move_right {
-                    self.focus = Focus::Tickets;
-                    // self.focus = Focus::Description;
+                    self.focus = Focus::Subtasks;
                     return Ok(EventState::Consumed);
                 }
 
@@ -963,6 +2643,29 @@ impl App {
                     log::debug!("got tickets focus event: {key:?}");
                     use TicketsAction::*;
                     match *action {
+                        AddComment => {
+                            if self.tickets.selected().is_some() {
+                                self.update_comments_view().await?;
+                                self.comment_add.clear();
+                                self.bulk_keys = self.tickets.marked_keys();
+                                self.focus = Focus::CommentsAdd;
+                            }
+                            return Ok(EventState::Consumed);
+                        }
+                        AssignTicket => {
+                            self.open_assignable_users().await?;
+                            self.focus = Focus::TicketAssign;
+                            return Ok(EventState::Consumed);
+                        }
+                        CreateTicket => {
+                            self.update_ticket_create_types().await?;
+                            self.focus = Focus::TicketCreate;
+                            return Ok(EventState::Consumed);
+                        }
+                        EditTicket => {
+                            self.edit_ticket()?;
+                            return Ok(EventState::Consumed);
+                        }
                         FocusRelation => {
                             self.focus = Focus::TicketRelation;
                             return Ok(EventState::Consumed);
@@ -993,6 +2696,53 @@ impl App {
                             self.focus = Focus::CommentsList;
                             return Ok(EventState::Consumed);
                         }
+                        OpenAttachments => {
+                            self.update_attachments_view();
+                            self.focus = Focus::Attachments;
+                            return Ok(EventState::Consumed);
+                        }
+                        OpenWatchers => {
+                            self.update_watchers_view().await?;
+                            self.focus = Focus::Watchers;
+                            return Ok(EventState::Consumed);
+                        }
+                        OpenFilters => {
+                            self.update_filters(false).await?;
+                            self.focus = Focus::Filters;
+                            return Ok(EventState::Consumed);
+                        }
+                        JqlSearch => {
+                            self.open_jql_prompt().await?;
+                            return Ok(EventState::Consumed);
+                        }
+                        OpenHistory => {
+                            if self.tickets.selected().is_some() {
+                                self.update_history(false).await?;
+                                self.focus = Focus::History;
+                            }
+                            return Ok(EventState::Consumed);
+                        }
+                        ViewTree => {
+                            self.open_ticket_tree().await?;
+                            return Ok(EventState::Consumed);
+                        }
+                        ViewBoard => {
+                            self.open_kanban().await?;
+                            return Ok(EventState::Consumed);
+                        }
+                        ViewSprints => {
+                            self.open_sprints().await?;
+                            return Ok(EventState::Consumed);
+                        }
+                        ToggleWatchedFilter => {
+                            self.toggle_watched_filter().await?;
+                            return Ok(EventState::Consumed);
+                        }
+                        OpenWorklogs => {
+                            self.update_worklogs_view().await?;
+                            self.focus = Focus::Worklogs;
+                            return Ok(EventState::Consumed);
+                        }
                         OpenProjects => {
                             self.focus = Focus::Projects;
                             return Ok(EventState::Consumed);
@@ -1041,3 +2791,11 @@ impl App {
         Ok(EventState::NotConsumed)
     }
 }
+
+/// The first line of an edited ticket is its summary, the rest its description.
+fn split_summary(contents: &str) -> (&str, &str) {
+    match contents.split_once('\n') {
+        Some((summary, description)) => (summary.trim(), description.trim()),
+        None => (contents.trim(), ""),
+    }
+}
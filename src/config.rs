@@ -1,19 +1,53 @@
-use std::{env, fs, process::exit};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 // use crate::{event::key::Key, log::LogLevel};
 use crate::event::key::Key;
 
-use serde::Deserialize;
+use anyhow::{anyhow, bail};
+use serde::{Deserialize, Serialize};
 
-#[cfg(test)] // TODO: What does this do?
-use serde::Serialize;
+/// Where Jira runs, which decides how jirust authenticates and which REST API it talks.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Deployment {
+    /// Atlassian Cloud: email and API token, REST API v3 with ADF bodies.
+    #[default]
+    Cloud,
+    /// Jira Server or Data Center: personal access token, REST API v2 with wiki markup.
+    #[serde(alias = "server")]
+    DataCenter,
+}
+
+impl Deployment {
+    pub fn api_version(self) -> &'static str {
+        match self {
+            Self::Cloud => "3",
+            Self::DataCenter => "2",
+        }
+    }
+}
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct JiraConfigFile {
     pub api_key: Option<String>,
+    /// Command printing the API token, such as `pass show jira`.
+    pub api_key_command: Option<String>,
+    /// File holding the API token, only readable by the user.
+    pub api_key_file: Option<String>,
+    pub attachments_dir: Option<String>,
     pub api_version: Option<String>,
     pub db_file: Option<bool>,
+    #[serde(default)]
+    pub deployment: Deployment,
     pub domain: String,
+    /// Logs in with OAuth 2.0 instead of an API token, Cloud only.
+    pub oauth: Option<JiraConfigOAuth>,
+    /// Only used by Cloud, Data Center tokens identify the user on their own.
+    #[serde(default)]
     pub user_email: String,
     pub projects: Option<JiraConfigProjects>,
     pub tickets: Option<JiraConfigTickets>,
@@ -25,16 +59,30 @@ pub struct JiraConfigTickets {
     pub current_sprint_tickets_only: Option<bool>,
     pub current_user_tickets_only: Option<bool>,
     // TODO: This functionality needs to be handled
+    #[allow(dead_code)]
     pub show_unassgined: Option<bool>,
     pub show_ticket_status: Option<Vec<String>>,
 }
 
+/// An OAuth 2.0 (3LO) app from the Atlassian developer console.
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct JiraConfigOAuth {
+    pub client_id: String,
+    /// Falls back on the `JIRA_OAUTH_CLIENT_SECRET` environment variable.
+    #[serde(default)]
+    pub client_secret: String,
+    /// Port of the `http://localhost:<port>/callback` redirect url registered for the app.
+    pub redirect_port: Option<u16>,
+    /// Space separated, `offline_access` is needed for the tokens to be refreshed.
+    pub scopes: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct JiraConfigProjects {
     pub default_projects: String,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone)]
 pub struct Config {
     #[serde(default)]
     pub key_config: KeyConfig,
@@ -43,6 +91,7 @@ pub struct Config {
     // pub log_level: LogLevel,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize, Clone)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct JiraConfig {
@@ -55,11 +104,18 @@ pub struct JiraConfig {
 #[derive(Debug, Deserialize, Clone)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct KeyConfig {
+    pub add: Key,
+    pub board_view: Key,
+    pub card_move_left: Key,
+    pub card_move_right: Key,
     pub enter: Key,
     pub esc: Key,
     pub exit: Key,
     pub filter: Key,
+    #[allow(dead_code)]
     pub filter_edit: Key,
+    pub filters: Key,
+    pub jql_search: Key,
     pub move_up: Key,
     pub move_down: Key,
     pub move_left: Key,
@@ -71,6 +127,7 @@ pub struct KeyConfig {
     pub previous: Key,
     pub previous_page: Key,
     pub quit: Key,
+    pub remove: Key,
     pub reset: Key,
     pub scroll_up: Key,
     pub scroll_down: Key,
@@ -82,19 +139,39 @@ pub struct KeyConfig {
     pub scroll_up_multiple_lines: Key,
     pub scroll_to_top: Key,
     pub scroll_to_bottom: Key,
+    pub sprints: Key,
+    pub ticket_assign: Key,
+    pub ticket_create: Key,
+    pub ticket_edit: Key,
+    pub ticket_history: Key,
+    pub ticket_mark: Key,
+    pub ticket_mark_all: Key,
+    pub ticket_mark_range: Key,
     pub ticket_transition: Key,
+    pub ticket_tree: Key,
     pub ticket_add_comments: Key,
+    pub ticket_view_attachments: Key,
     pub ticket_view_comments: Key,
+    pub ticket_view_watchers: Key,
+    pub ticket_view_worklogs: Key,
+    pub ticket_watch: Key,
+    pub watched_filter: Key,
 }
 
 impl Default for KeyConfig {
     fn default() -> Self {
         Self {
+            add: Key::Char('a'),
+            board_view: Key::Char('b'),
+            card_move_left: Key::Char('H'),
+            card_move_right: Key::Char('L'),
             enter: Key::Enter,
             esc: Key::Esc,
             exit: Key::Ctrl('c'),
             filter: Key::Char('/'),
             filter_edit: Key::Char('e'),
+            filters: Key::Char('v'),
+            jql_search: Key::Char(':'),
             move_up: Key::Up,
             move_down: Key::Down,
             move_left: Key::Left,
@@ -108,6 +185,7 @@ impl Default for KeyConfig {
             previous: Key::BackTab,
             previous_page: Key::Char('N'),
             quit: Key::Char('q'),
+            remove: Key::Char('d'),
             reset: Key::Char('r'),
             scroll_up: Key::Char('k'),
             scroll_down: Key::Char('j'),
@@ -117,73 +195,165 @@ impl Default for KeyConfig {
             scroll_up_multiple_lines: Key::Ctrl('u'),
             scroll_to_top: Key::Char('g'),
             scroll_to_bottom: Key::Char('G'),
+            sprints: Key::Char('S'),
+            ticket_assign: Key::Char('A'),
+            ticket_create: Key::Char('a'),
+            ticket_edit: Key::Char('E'),
+            ticket_history: Key::Char('y'),
+            ticket_mark: Key::Char('m'),
+            ticket_mark_all: Key::Char('M'),
+            ticket_mark_range: Key::Char('V'),
             ticket_transition: Key::Char('t'),
+            ticket_tree: Key::Char('T'),
             ticket_add_comments: Key::Char('C'),
+            ticket_view_attachments: Key::Char('F'),
             ticket_view_comments: Key::Char('c'),
+            ticket_view_watchers: Key::Char('W'),
+            ticket_view_worklogs: Key::Char('w'),
+            ticket_watch: Key::Char('w'),
+            watched_filter: Key::Char('f'),
         }
     }
 }
 
-impl Default for JiraConfigFile {
-    fn default() -> Self {
-        let home_directory = env!("HOME");
-        let filename = format!("{}/.config/jirust/config.toml", home_directory);
-        let contents = match fs::read_to_string(filename.clone()) {
-            // If successful return the files text as `contents`.
-            // `c` is a local variable.
-            Ok(c) => c,
-            // Handle the `error` case.
-            Err(_) => {
-                // Write `msg` to `stderr`.
-                eprintln!("Could not read file `{}`", filename);
-                // Exit the program with exit code `1`.
-                exit(1);
-            }
-        };
+impl JiraConfigFile {
+    /// Reads `~/.config/jirust/config.toml` and resolves the credentials it points to.
+    pub fn load() -> anyhow::Result<Self> {
+        let filename = config_dir().join("config.toml");
+        let contents = fs::read_to_string(&filename)
+            .map_err(|e| anyhow!("Could not read file `{}` - {}", filename.display(), e))?;
+        let mut data: JiraConfigFile = toml::from_str(&contents)
+            .map_err(|e| anyhow!("Unable to load data from `{}` - {}", filename.display(), e))?;
 
-        // Use a `match` block to return the
-        // file `contents` as a `Data struct: Ok(d)`
-        // or handle any `errors: Err(_)`.
-        let data: JiraConfigFile = match toml::from_str(&contents) {
-            // If successful, return data as `Data` struct.
-            // `d` is a local variable.
-            Ok(d) => d,
-            // Handle the `error` case.
-            Err(e) => {
-                // Write `msg` to `stderr`.
-                eprintln!("Unable to load data from `{}` - {}", filename, e);
-                // Exit the program with exit code `1`.
-                exit(1);
+        if let Some(oauth) = data.oauth.as_mut() {
+            if data.deployment != Deployment::Cloud {
+                bail!("OAuth login is only available on Jira Cloud");
+            }
+            if oauth.client_secret.is_empty() {
+                oauth.client_secret = env::var("JIRA_OAUTH_CLIENT_SECRET").unwrap_or_default();
             }
+        }
+
+        // OAuth logins don't need an API token
+        data.api_key = match data.resolve_api_key()? {
+            Some(key) => Some(key),
+            None if data.oauth.is_some() => None,
+            None => bail!(
+                "No Jira API token found. Set `api_key_command` or `api_key_file` in {}, \
+                 or the JIRA_API_KEY environment variable",
+                filename.display()
+            ),
         };
 
-        let db_file = data.db_file;
-        let domain = data.domain;
-        let jira_user_email = data.user_email;
+        data.api_version = Some(
+            data.api_version
+                .take()
+                .unwrap_or_else(|| data.deployment.api_version().to_string()),
+        );
 
-        let jira_api_key = match env::var("JIRA_API_KEY") {
-            Ok(v) => v,
-            Err(_e) => {
-                panic!("Environment variable JIRA_API_KEY is not set")
-            }
-        };
+        Ok(data)
+    }
 
-        let jira_api_version = "3".to_string();
+    /// The API token from `api_key_command`, then `api_key_file`, then `JIRA_API_KEY`.
+    fn resolve_api_key(&self) -> anyhow::Result<Option<String>> {
+        if let Some(command) = &self.api_key_command {
+            return api_key_from_command(command).map(Some);
+        }
+        if let Some(file) = &self.api_key_file {
+            return api_key_from_file(&expand_home(file)).map(Some);
+        }
+        Ok(env::var("JIRA_API_KEY").ok().filter(|key| !key.is_empty()))
+    }
+}
 
-        Self {
-            api_key: Some(jira_api_key),
-            api_version: Some(jira_api_version),
-            db_file,
-            domain,
-            projects: data.projects,
-            tickets: data.tickets,
-            user_email: jira_user_email,
+/// Runs `command` through the shell, the token being the first line it prints.
+fn api_key_from_command(command: &str) -> anyhow::Result<String> {
+    #[cfg(unix)]
+    let output = Command::new("sh").arg("-c").arg(command).output();
+    #[cfg(windows)]
+    let output = Command::new("cmd").arg("/C").arg(command).output();
+    let output = output.map_err(|e| anyhow!("Could not run api_key_command `{command}` - {e}"))?;
+    if !output.status.success() {
+        bail!(
+            "api_key_command `{command}` failed with {} - {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let stdout = String::from_utf8(output.stdout)?;
+    match stdout.lines().next().map(str::trim) {
+        Some(key) if !key.is_empty() => Ok(key.to_string()),
+        _ => bail!("api_key_command `{command}` printed no token"),
+    }
+}
+
+/// Reads the token from `path`, which other users must not be able to read.
+fn api_key_from_file(path: &Path) -> anyhow::Result<String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let metadata = fs::metadata(path)
+            .map_err(|e| anyhow!("Could not read api_key_file `{}` - {}", path.display(), e))?;
+        let mode = metadata.permissions().mode();
+        if mode & 0o077 != 0 {
+            bail!(
+                "api_key_file `{}` can be read by other users (mode {:o}), run `chmod 600` on it",
+                path.display(),
+                mode & 0o777
+            );
         }
     }
+    let contents = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Could not read api_key_file `{}` - {}", path.display(), e))?;
+    match contents.trim() {
+        "" => bail!("api_key_file `{}` is empty", path.display()),
+        key => Ok(key.to_string()),
+    }
+}
+
+/// Expands a leading `~` to `$HOME`.
+pub fn expand_home(input: &str) -> PathBuf {
+    match (input.strip_prefix('~'), env::var("HOME")) {
+        (Some(rest), Ok(home)) => PathBuf::from(format!("{}{}", home, rest)),
+        _ => PathBuf::from(input),
+    }
+}
+
+/// `~/.config/jirust`, where the config file and the OAuth tokens live.
+pub fn config_dir() -> PathBuf {
+    let home_directory = env::var("HOME").unwrap_or_else(|_| env!("HOME").to_string());
+    PathBuf::from(home_directory).join(".config").join("jirust")
 }
 
 impl Config {
     pub fn new() -> anyhow::Result<Self> {
-        Ok(Config::default())
+        Ok(Self {
+            key_config: KeyConfig::default(),
+            jira_config: JiraConfigFile::load()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::api_key_from_file;
+    use std::{env, fs, process};
+
+    #[cfg(unix)]
+    #[test]
+    fn api_key_file_must_be_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = env::temp_dir().join(format!("jirust-api-key-{}", process::id()));
+        fs::write(&path, "secret\n").unwrap();
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        let err = api_key_from_file(&path).unwrap_err();
+        assert!(err.to_string().contains("can be read by other users"));
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+        let key = api_key_from_file(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(key.unwrap(), "secret");
     }
 }
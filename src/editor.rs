@@ -0,0 +1,94 @@
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+use anyhow::anyhow;
+
+/// A temporary file to open in `$EDITOR` and the ticket its contents belong to.
+pub struct EditorRequest {
+    contents: String,
+    path: PathBuf,
+    pub ticket_key: String,
+}
+
+impl EditorRequest {
+    /// Writes `contents` to a new file only the user can read, never reusing an existing path.
+    pub fn new(ticket_key: &str, contents: String) -> anyhow::Result<Self> {
+        let mut attempt = 0;
+        let (path, mut file) = loop {
+            let path = env::temp_dir().join(format!(
+                "jirust-{ticket_key}-{}-{attempt}.md",
+                process::id()
+            ));
+            let mut options = OpenOptions::new();
+            // create_new refuses existing files and symlinks alike
+            options.write(true).create_new(true);
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::OpenOptionsExt;
+                options.mode(0o600);
+            }
+            match options.open(&path) {
+                Ok(file) => break (path, file),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists && attempt < 100 => attempt += 1,
+                Err(e) => return Err(e.into()),
+            }
+        };
+        if let Err(e) = file.write_all(contents.as_bytes()) {
+            let _ = fs::remove_file(&path);
+            return Err(e.into());
+        }
+        Ok(Self {
+            contents,
+            path,
+            ticket_key: ticket_key.to_string(),
+        })
+    }
+
+    pub fn open(&self) -> anyhow::Result<()> {
+        let editor = env::var("VISUAL")
+            .or_else(|_| env::var("EDITOR"))
+            .unwrap_or_else(|_| "vi".to_string());
+        let mut args = editor.split_whitespace();
+        let result = match args.next() {
+            None => Err(anyhow!("EDITOR is empty")),
+            Some(program) => match Command::new(program).args(args).arg(&self.path).status() {
+                Ok(status) if status.success() => Ok(()),
+                Ok(status) => Err(anyhow!("{editor} exited with {status}")),
+                Err(e) => Err(e.into()),
+            },
+        };
+        // The edits are dropped, so is the file
+        if result.is_err() {
+            let _ = fs::remove_file(&self.path);
+        }
+        result
+    }
+
+    pub fn contents(&self) -> &str {
+        &self.contents
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the edited file, which stays until `remove` so failed saves don't lose the edits.
+    /// Returns `None` if nothing was changed.
+    pub fn read(&self) -> anyhow::Result<Option<String>> {
+        let edited = fs::read_to_string(&self.path)?;
+        if edited == self.contents {
+            return Ok(None);
+        }
+        Ok(Some(edited))
+    }
+
+    pub fn remove(&self) -> anyhow::Result<()> {
+        fs::remove_file(&self.path)?;
+        Ok(())
+    }
+}
@@ -1,2 +1,3 @@
+#[allow(clippy::module_inception)]
 pub mod event;
 pub mod key;
@@ -1,9 +1,17 @@
 use crate::event::key::Key;
 use crossterm::event;
-use std::{sync::mpsc, thread, time::Duration};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct EventConfig {
+    #[allow(dead_code)]
     pub exit_key: Key,
     pub tick_rate: Duration,
 }
@@ -24,6 +32,9 @@ pub enum Event<I> {
 }
 
 pub struct Events {
+    paused: Arc<AtomicBool>,
+    /// Held by the input thread while it polls the terminal.
+    reading: Arc<Mutex<()>>,
     rx: mpsc::Receiver<Event<Key>>,
     _tx: mpsc::Sender<Event<Key>>,
 }
@@ -39,20 +50,50 @@ impl Events {
     pub fn with_config(config: EventConfig) -> Events {
         let (tx, rx) = mpsc::channel();
 
+        let paused = Arc::new(AtomicBool::new(false));
+        let reading = Arc::new(Mutex::new(()));
+
         let event_tx = tx.clone();
+        let event_paused = paused.clone();
+        let event_reading = reading.clone();
         thread::spawn(move || loop {
+            let guard = event_reading.lock().unwrap();
+            if event_paused.load(Ordering::SeqCst) {
+                drop(guard);
+                thread::sleep(config.tick_rate);
+                continue;
+            }
+            let mut input = None;
             if event::poll(config.tick_rate).unwrap() {
                 if let event::Event::Key(key) = event::read().unwrap() {
-                    let key = Key::from(key);
-
-                    event_tx.send(Event::Input(key)).unwrap();
+                    input = Some(Key::from(key));
                 }
             }
+            drop(guard);
 
+            if let Some(key) = input {
+                event_tx.send(Event::Input(key)).unwrap();
+            }
             event_tx.send(Event::Tick).unwrap();
         });
 
-        Events { rx, _tx: tx }
+        Events {
+            paused,
+            reading,
+            rx,
+            _tx: tx,
+        }
+    }
+
+    /// Stops reading the terminal so another process (such as `$EDITOR`) can own stdin.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+        // Wait out a poll already in flight, the input thread sees the flag before its next one
+        drop(self.reading.lock());
+    }
+
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
     }
 
     pub fn next(&self) -> Result<Event<Key>, mpsc::RecvError> {
@@ -10,18 +10,53 @@ pub type SurrealAny = Surreal<Any>;
 use crate::config::JiraConfigProjects;
 use crate::config::JiraConfigTickets;
 
+use self::agile::{Board, BoardColumn, Sprint};
+use self::fields::{CreateFields, CreateIssueTypes};
+use self::filters::Filter;
 use self::projects::Project;
-use self::tickets::TicketData;
-use self::{
-    auth::{jira_authentication, JiraClient},
-    projects::JiraProjects,
-    tickets::JiraTickets,
-};
+use self::tickets::{Components, History, LinkType, TicketData};
+use self::{auth::JiraClient, projects::JiraProjects, tickets::JiraTickets};
 
+pub mod adf;
+pub mod agile;
 pub mod auth;
+pub mod fields;
+pub mod filters;
+pub mod oauth;
 pub mod projects;
 pub mod tickets;
+pub mod users;
+pub mod wiki;
 
+/// Record in the `hierarchy` table, the parents whose children are all in the tickets cache.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ParentTicket {
+    key: String,
+}
+
+/// Record in the `queries` table, the keys of a JQL query's results in Jira's order,
+/// as far as the pages were fetched.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TicketQuery {
+    jql: String,
+    keys: Vec<String>,
+    total: u32,
+}
+
+/// Record in the `changelogs` table, the history of a ticket newest first.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TicketChangelog {
+    key: String,
+    histories: Vec<History>,
+}
+
+/// Record in the `watched` table, the set of tickets the current user watches.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WatchedTicket {
+    pub key: String,
+}
+
+#[allow(dead_code)]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DBTicketData {
     pub id: String,
@@ -44,15 +79,11 @@ pub struct Jira {
 
 impl Jira {
     pub async fn new(
-        domain: &str,
-        api_key: &str,
-        api_version: &str,
-        current_user_email: &str,
+        client: JiraClient,
         user_config_cache: &Option<bool>,
         user_config_project: &Option<JiraConfigProjects>,
         user_config_tickets: &Option<JiraConfigTickets>,
     ) -> anyhow::Result<Jira, anyhow::Error> {
-        let auth = jira_authentication(domain, api_key, api_version, current_user_email);
         let projects: JiraProjects = JiraProjects::new().await?;
         let tickets: JiraTickets = JiraTickets::new().await?;
         let db = match user_config_cache {
@@ -62,7 +93,7 @@ impl Jira {
         db.use_ns("noc").use_db("database").await?;
 
         Ok(Self {
-            client: auth,
+            client,
             db,
             projects,
             project_start_at: 0,
@@ -77,6 +108,8 @@ impl Jira {
 
     pub async fn clear_projects_table(&mut self) -> anyhow::Result<()> {
         let _delete_projects: Vec<Project> = self.db.delete("projects").await?;
+        let _delete_boards: Vec<serde_json::Value> = self.db.delete("boards").await?;
+        let _delete_sprints: Vec<serde_json::Value> = self.db.delete("sprints").await?;
         Ok(())
     }
 
@@ -133,6 +166,37 @@ impl Jira {
         let projects: Vec<Project> = query.take(0)?;
         debug!("Projects found on cache {:?}", projects);
 
+        // Data Center has no paginated project search, it lists every project at once
+        if projects.is_empty() && !self.client.is_cloud() {
+            let response = self.client.get_from_jira_api("project").await?;
+            let mut values: Vec<Project> = serde_json::from_str(&response)?;
+            if let Some(projects) = self.user_config_projects.as_ref() {
+                let keys: Vec<&str> = projects
+                    .default_projects
+                    .split(['&', ','])
+                    .map(|key| key.trim().trim_start_matches("keys="))
+                    .collect();
+                values.retain(|project| keys.contains(&project.key.as_str()));
+            }
+            for project in &values {
+                let _projects_insert: Project = self
+                    .db
+                    .update(("projects", &project.key))
+                    .content(project)
+                    .await?
+                    .expect("projects inserted into db");
+            }
+            self.projects = JiraProjects {
+                is_last: true,
+                max_results: values.len() as u32,
+                next_page: None,
+                start_at: 0,
+                total: values.len() as u32,
+                values,
+            };
+            return Ok(self.projects.values.clone());
+        }
+
         // Get initial projects request
         if projects.is_empty() {
             let jira_url = self.client.get_domain();
@@ -140,8 +204,7 @@ impl Jira {
                 "{}/project/search?maxResults={}&startAt=0",
                 jira_url, self.project_max_results
             );
-            if self.user_config_projects.is_some() {
-                let projects = self.user_config_projects.as_ref().unwrap();
+            if let Some(projects) = self.user_config_projects.as_ref() {
                 url = format!(
                     "{}/project/search?keys={}",
                     jira_url, projects.default_projects
@@ -172,6 +235,9 @@ impl Jira {
 
     pub async fn clear_tickets_table(&mut self) -> anyhow::Result<()> {
         let _delete_projects: Vec<TicketData> = self.db.delete("tickets").await?;
+        let _delete_hierarchy: Vec<ParentTicket> = self.db.delete("hierarchy").await?;
+        let _delete_queries: Vec<TicketQuery> = self.db.delete("queries").await?;
+        let _delete_changelogs: Vec<TicketChangelog> = self.db.delete("changelogs").await?;
         Ok(())
     }
 
@@ -232,6 +298,7 @@ impl Jira {
         for ticket in self.tickets.issues.clone() {
             // let db = self.db.clone();
             // let tkt = ticket.clone();
+            self.record_watched(&ticket).await?;
             let tickets_insert: TicketData = self.db
                 .update(("tickets", &ticket.key))
                 .content(ticket)
@@ -297,6 +364,140 @@ impl Jira {
         Ok(self.tickets.issues.clone())
     }
 
+    /// Page of the tickets matching `jql` at `tickets_start_at`, from the cache when it was
+    /// fetched before.
+    pub async fn get_jql_tickets(
+        &mut self,
+        jql: &str,
+    ) -> anyhow::Result<Vec<TicketData>, anyhow::Error> {
+        let start_at = self.tickets_start_at as usize;
+        let end = start_at + self.tickets_max_results as usize;
+        let query: Option<TicketQuery> = self.db.select(("queries", jql)).await?;
+        if let Some(query) = &query {
+            let fetched = query.keys.len();
+            if fetched >= end || fetched as u32 == query.total {
+                let keys = query.keys[start_at.min(fetched)..end.min(fetched)].to_vec();
+                let mut result = self
+                    .db
+                    .query("SELECT * FROM tickets WHERE key IN $keys")
+                    .bind(("keys", &keys))
+                    .await?;
+                let mut tickets: Vec<TicketData> = result.take(0)?;
+                tickets.sort_by_key(|t| keys.iter().position(|k| *k == t.key));
+                self.tickets.total = query.total;
+                self.tickets.issues = tickets;
+                return Ok(self.tickets.issues.clone());
+            }
+        }
+
+        debug!("Retrieve tickets from API for {jql}");
+        let page = self
+            .tickets
+            .search_jql_page(jql, self.tickets_start_at, self.tickets_max_results, &self.client)
+            .await?;
+        let mut keys = query.map(|q| q.keys).unwrap_or_default();
+        keys.truncate(start_at);
+        for ticket in &page.issues {
+            keys.push(ticket.key.clone());
+            self.record_watched(ticket).await?;
+            let _ticket_record: Option<TicketData> = self
+                .db
+                .update(("tickets", &ticket.key))
+                .content(ticket)
+                .await?;
+        }
+        let _query_record: Option<TicketQuery> = self
+            .db
+            .update(("queries", jql))
+            .content(TicketQuery {
+                jql: jql.to_string(),
+                keys,
+                total: page.total,
+            })
+            .await?;
+        self.tickets = page;
+        Ok(self.tickets.issues.clone())
+    }
+
+    pub async fn get_next_jql_page(
+        &mut self,
+        jql: &str,
+    ) -> anyhow::Result<Vec<TicketData>, anyhow::Error> {
+        if self.tickets_start_at + self.tickets_max_results >= self.tickets.total {
+            return Ok(self.tickets.issues.clone());
+        }
+        self.tickets_start_at += self.tickets_max_results;
+        self.get_jql_tickets(jql).await
+    }
+
+    pub async fn get_previous_jql_page(
+        &mut self,
+        jql: &str,
+    ) -> anyhow::Result<Vec<TicketData>, anyhow::Error> {
+        self.tickets_start_at = self
+            .tickets_start_at
+            .saturating_sub(self.tickets_max_results);
+        self.get_jql_tickets(jql).await
+    }
+
+    /// The user's favourite filters, from the cache unless `refresh` is set.
+    pub async fn get_favourite_filters(
+        &self,
+        refresh: bool,
+    ) -> anyhow::Result<Vec<Filter>, anyhow::Error> {
+        let mut filters: Vec<Filter> = Vec::new();
+        if refresh {
+            let _delete_filters: Vec<serde_json::Value> = self.db.delete("filters").await?;
+        } else {
+            let mut query = self.db.query("SELECT * OMIT id FROM filters").await?;
+            filters = query.take(0)?;
+        }
+        if filters.is_empty() {
+            filters = Filter::favourites(&self.client).await?;
+            for filter in &filters {
+                let _filter_record: Option<serde_json::Value> = self
+                    .db
+                    .update(("filters", &filter.filter_id))
+                    .content(filter)
+                    .await?;
+            }
+        }
+        filters.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(filters)
+    }
+
+    /// History of `ticket`, from the cache unless `refresh` is set.
+    pub async fn get_changelog(
+        &self,
+        ticket: &TicketData,
+        refresh: bool,
+    ) -> anyhow::Result<Vec<History>, anyhow::Error> {
+        if !refresh {
+            let cached: Option<TicketChangelog> =
+                self.db.select(("changelogs", &ticket.key)).await?;
+            if let Some(changelog) = cached {
+                return Ok(changelog.histories);
+            }
+        }
+        let histories = ticket.get_changelog(&self.client).await?;
+        let _changelog_record: Option<TicketChangelog> = self
+            .db
+            .update(("changelogs", &ticket.key))
+            .content(TicketChangelog {
+                key: ticket.key.clone(),
+                histories: histories.clone(),
+            })
+            .await?;
+        Ok(histories)
+    }
+
+    /// Queries run before, from the cache.
+    pub async fn get_jql_history(&self) -> anyhow::Result<Vec<String>, anyhow::Error> {
+        let mut query = self.db.query("SELECT VALUE jql FROM queries").await?;
+        let history: Vec<String> = query.take(0)?;
+        Ok(history)
+    }
+
     pub async fn search_cache_ticket(
         &mut self,
         ticket_key: &str,
@@ -324,6 +525,10 @@ impl Jira {
             .search_jira_ticket_api(ticket_key, &self.client)
             .await?;
         self.jira_project_api(&ticket.fields.project.key).await?;
+        self.record_watched(&ticket).await?;
+        // The ticket changed, its cached history is behind.
+        let _delete_changelog: Option<TicketChangelog> =
+            self.db.delete(("changelogs", ticket_key)).await?;
         let update_ticket_record: TicketData = self
             .db
             .update(("tickets", ticket_key))
@@ -336,6 +541,80 @@ impl Jira {
         Ok(update_ticket_record)
     }
 
+    /// Children of `parent_key` from the cache, queried with `parent = KEY` the first time.
+    pub async fn get_child_tickets(
+        &self,
+        parent_key: &str,
+    ) -> anyhow::Result<Vec<TicketData>, anyhow::Error> {
+        let loaded: Option<ParentTicket> = self.db.select(("hierarchy", parent_key)).await?;
+        if loaded.is_some() {
+            let mut query = self
+                .db
+                .query("SELECT * FROM tickets WHERE fields.parent.key = type::string($parent_key)")
+                .bind(("parent_key", parent_key))
+                .await?;
+            let mut tickets: Vec<TicketData> = query.take(0)?;
+            // Shorter keys first, so PROJ-9 comes before PROJ-10 as in Jira's ORDER BY key.
+            tickets.sort_by(|a, b| (a.key.len(), &a.key).cmp(&(b.key.len(), &b.key)));
+            return Ok(tickets);
+        }
+        self.record_child_tickets(parent_key).await
+    }
+
+    /// Fetches the children of `parent_key` from Jira and records them in the cache.
+    pub async fn record_child_tickets(
+        &self,
+        parent_key: &str,
+    ) -> anyhow::Result<Vec<TicketData>, anyhow::Error> {
+        let jql = format!("parent = {} ORDER BY key", parent_key);
+        let tickets = self.tickets.search_all_jql(&jql, &self.client).await?;
+        for ticket in &tickets {
+            self.record_watched(ticket).await?;
+            let _ticket_record: Option<TicketData> = self
+                .db
+                .update(("tickets", &ticket.key))
+                .content(ticket)
+                .await?;
+        }
+        let _parent_record: Option<ParentTicket> = self
+            .db
+            .update(("hierarchy", parent_key))
+            .content(ParentTicket {
+                key: parent_key.to_string(),
+            })
+            .await?;
+        Ok(tickets)
+    }
+
+    /// Keeps the `watched` set in line with the ticket's watch status from Jira.
+    async fn record_watched(&self, ticket: &TicketData) -> anyhow::Result<()> {
+        let watching = match &ticket.fields.watches {
+            None => return Ok(()),
+            Some(watches) => watches.is_watching,
+        };
+        let _record: Option<WatchedTicket> = if watching {
+            self.db
+                .update(("watched", &ticket.key))
+                .content(WatchedTicket {
+                    key: ticket.key.clone(),
+                })
+                .await?
+        } else {
+            self.db.delete(("watched", &ticket.key)).await?
+        };
+        Ok(())
+    }
+
+    /// Cached tickets in the `watched` set, available without reaching Jira.
+    pub async fn get_watched_tickets(&self) -> anyhow::Result<Vec<TicketData>, anyhow::Error> {
+        let mut query = self
+            .db
+            .query("SELECT * FROM tickets WHERE key IN (SELECT VALUE key FROM watched)")
+            .await?;
+        let tickets: Vec<TicketData> = query.take(0)?;
+        Ok(tickets)
+    }
+
     pub async fn search_cache_projects(
         &mut self,
         project_key: &str,
@@ -367,4 +646,223 @@ impl Jira {
 
         Ok(update_project_record)
     }
+
+    pub async fn get_project_components(
+        &self,
+        project_key: &str,
+    ) -> anyhow::Result<Vec<Components>, anyhow::Error> {
+        self.projects
+            .get_project_components(project_key, &self.client)
+            .await
+    }
+
+    /// Boards of the project from Jira, the cache is only used when Jira can't be reached.
+    pub async fn get_boards(&self, project_key: &str) -> anyhow::Result<Vec<Board>, anyhow::Error> {
+        let mut boards = match Board::list_for_project(project_key, &self.client).await {
+            Ok(boards) => {
+                self.db
+                    .query("DELETE boards WHERE project_key = type::string($project_key)")
+                    .bind(("project_key", project_key))
+                    .await?;
+                for board in &boards {
+                    let _board_record: Option<serde_json::Value> = self
+                        .db
+                        .update(("boards", board.board_id))
+                        .content(board)
+                        .await?;
+                }
+                boards
+            }
+            Err(err) => {
+                let mut query = self
+                    .db
+                    .query("SELECT * OMIT id FROM boards WHERE project_key = type::string($project_key)")
+                    .bind(("project_key", project_key))
+                    .await?;
+                let boards: Vec<Board> = query.take(0)?;
+                if boards.is_empty() {
+                    return Err(err);
+                }
+                debug!("Boards of {project_key} from cache: {err}");
+                boards
+            }
+        };
+        boards.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(boards)
+    }
+
+    /// Sprints of the board from Jira, so new and closed sprints show up. The cache is only
+    /// used when Jira can't be reached.
+    pub async fn get_sprints(&self, board: &Board) -> anyhow::Result<Vec<Sprint>, anyhow::Error> {
+        let mut sprints = match board.sprints(&self.client).await {
+            Ok(sprints) => {
+                self.db
+                    .query("DELETE sprints WHERE board_id = type::number($board_id)")
+                    .bind(("board_id", board.board_id))
+                    .await?;
+                for sprint in &sprints {
+                    let _sprint_record: Option<serde_json::Value> = self
+                        .db
+                        .update(("sprints", sprint.sprint_id))
+                        .content(sprint)
+                        .await?;
+                }
+                sprints
+            }
+            Err(err) => {
+                let mut query = self
+                    .db
+                    .query("SELECT * OMIT id FROM sprints WHERE board_id = type::number($board_id)")
+                    .bind(("board_id", board.board_id))
+                    .await?;
+                let sprints: Vec<Sprint> = query.take(0)?;
+                if sprints.is_empty() {
+                    return Err(err);
+                }
+                debug!("Sprints of board {} from cache: {err}", board.board_id);
+                sprints
+            }
+        };
+        sprints.sort_by(|a, b| {
+            a.state_rank()
+                .cmp(&b.state_rank())
+                .then_with(|| b.start_date.cmp(&a.start_date))
+        });
+        Ok(sprints)
+    }
+
+    /// Tickets of the sprint from Jira, recorded into the tickets cache.
+    pub async fn get_sprint_tickets(
+        &self,
+        sprint: &Sprint,
+    ) -> anyhow::Result<Vec<TicketData>, anyhow::Error> {
+        let tickets = sprint.tickets(&self.client).await?;
+        for ticket in &tickets {
+            self.record_watched(ticket).await?;
+            let _ticket_record: Option<TicketData> = self
+                .db
+                .update(("tickets", &ticket.key))
+                .content(ticket)
+                .await?;
+        }
+        Ok(tickets)
+    }
+
+    pub async fn get_board_columns(
+        &self,
+        board: &Board,
+    ) -> anyhow::Result<Vec<BoardColumn>, anyhow::Error> {
+        board.columns(&self.client).await
+    }
+
+    /// Labels already seen on cached tickets, merged with Jira's label suggestions.
+    pub async fn get_label_suggestions(&self) -> anyhow::Result<Vec<String>, anyhow::Error> {
+        let mut query = self.db.query("SELECT VALUE fields.labels FROM tickets").await?;
+        let cached: Vec<Vec<String>> = query.take(0)?;
+        let mut labels: Vec<String> = cached.into_iter().flatten().collect();
+        labels.extend(
+            self.tickets
+                .get_label_suggestions("", &self.client)
+                .await?,
+        );
+        labels.sort();
+        labels.dedup();
+        Ok(labels)
+    }
+
+    pub async fn get_link_types(&self) -> anyhow::Result<Vec<LinkType>, anyhow::Error> {
+        self.tickets.get_link_types(&self.client).await
+    }
+
+    /// Cached tickets as "KEY summary", used to complete link targets.
+    pub async fn get_cached_ticket_keys(&self) -> anyhow::Result<Vec<String>, anyhow::Error> {
+        let mut query = self
+            .db
+            .query("SELECT VALUE string::concat(key, ' ', fields.summary) FROM tickets")
+            .await?;
+        let mut keys: Vec<String> = query.take(0)?;
+        keys.sort();
+        Ok(keys)
+    }
+
+    pub async fn get_create_issue_types(
+        &self,
+        project_key: &str,
+    ) -> anyhow::Result<CreateIssueTypes, anyhow::Error> {
+        self.tickets
+            .get_create_issue_types(project_key, &self.client)
+            .await
+    }
+
+    pub async fn get_create_fields(
+        &self,
+        project_key: &str,
+        issue_type_id: &str,
+    ) -> anyhow::Result<CreateFields, anyhow::Error> {
+        self.tickets
+            .get_create_fields(project_key, issue_type_id, &self.client)
+            .await
+    }
+
+    pub async fn create_ticket(
+        &mut self,
+        fields: serde_json::Map<String, serde_json::Value>,
+    ) -> anyhow::Result<TicketData, anyhow::Error> {
+        let created = self
+            .tickets
+            .create_jira_ticket_api(fields, &self.client)
+            .await?;
+        debug!("Created ticket {:?}", created);
+        self.jira_ticket_api(&created.key).await
+    }
+
+    /// Creates a subtask of `parent` per summary with the project's subtask issue type.
+    /// Returns the created tickets and a message per summary Jira refused.
+    pub async fn create_subtasks(
+        &mut self,
+        parent: &TicketData,
+        summaries: &[String],
+    ) -> anyhow::Result<(Vec<TicketData>, Vec<String>), anyhow::Error> {
+        let project_key = parent.fields.project.key.clone();
+        let issue_types = self.get_create_issue_types(&project_key).await?;
+        let subtask_type = issue_types
+            .issue_types
+            .into_iter()
+            .find(|t| t.subtask)
+            .ok_or_else(|| anyhow::anyhow!("Project {} has no subtask issue type", project_key))?;
+        let issue_fields = summaries
+            .iter()
+            .map(|summary| {
+                serde_json::json!({
+                    "project": { "key": project_key },
+                    "parent": { "key": parent.key },
+                    "issuetype": { "id": subtask_type.id },
+                    "summary": summary,
+                })
+            })
+            .collect();
+        let created = self
+            .tickets
+            .create_jira_tickets_bulk(issue_fields, &self.client)
+            .await?;
+        debug!("Created subtasks {:?}", created);
+        let mut tickets = Vec::new();
+        for ticket in &created.issues {
+            tickets.push(self.jira_ticket_api(&ticket.key).await?);
+        }
+        // The parent's subtasks field lists the new tickets once it is fetched again.
+        self.jira_ticket_api(&parent.key).await?;
+        let failures = created
+            .errors
+            .iter()
+            .map(|error| {
+                let summary = summaries
+                    .get(error.failed_element_number)
+                    .map(String::as_str)
+                    .unwrap_or_default();
+                format!("\"{}\": {}", summary, error.message())
+            })
+            .collect();
+        Ok((tickets, failures))
+    }
 }
@@ -0,0 +1,713 @@
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag};
+use serde_json::{json, Map, Value};
+
+/// Converts markdown into an Atlassian document format document.
+///
+/// Links to `accountid:<id>` become mention nodes, task lists become ADF task lists
+/// and fenced code keeps its language.
+pub fn markdown_to_adf(markdown: &str) -> Value {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut writer = AdfWriter::new();
+    for event in Parser::new_ext(markdown, options) {
+        writer.event(event);
+    }
+    writer.finish()
+}
+
+/// Marks a paragraph opened for loose inline content, e.g. the text of a tight list item.
+const IMPLICIT: &str = "_implicit";
+/// Marks a list item started with a task list marker, holding its state.
+const TASK: &str = "_task";
+
+struct AdfWriter {
+    /// Nodes being built, the document at the bottom.
+    stack: Vec<Value>,
+    marks: Vec<Value>,
+    code: Option<String>,
+    /// Account id and text of the mention link being read.
+    mention: Option<(String, String)>,
+    in_table_head: bool,
+    local_ids: usize,
+}
+
+fn node(node_type: &str) -> Value {
+    json!({ "type": node_type, "content": [] })
+}
+
+fn node_type(node: &Value) -> &str {
+    node.get("type")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default()
+}
+
+fn content_mut(node: &mut Value) -> &mut Vec<Value> {
+    if node.get("content").and_then(|c| c.as_array()).is_none() {
+        node["content"] = json!([]);
+    }
+    node["content"]
+        .as_array_mut()
+        .expect("content was just made an array")
+}
+
+impl AdfWriter {
+    fn new() -> Self {
+        Self {
+            stack: vec![json!({ "version": 1, "type": "doc", "content": [] })],
+            marks: Vec::new(),
+            code: None,
+            mention: None,
+            in_table_head: false,
+            local_ids: 0,
+        }
+    }
+
+    fn finish(mut self) -> Value {
+        self.close_implicit();
+        while self.stack.len() > 1 {
+            self.close();
+        }
+        self.stack.pop().expect("the document is never closed")
+    }
+
+    fn local_id(&mut self) -> String {
+        self.local_ids += 1;
+        format!("jirust-{}", self.local_ids)
+    }
+
+    fn top(&mut self) -> &mut Value {
+        self.stack.last_mut().expect("the document is never closed")
+    }
+
+    fn open(&mut self, node: Value) {
+        self.close_implicit();
+        self.stack.push(node);
+    }
+
+    fn close(&mut self) {
+        let mut closed = match self.stack.pop() {
+            Some(closed) => closed,
+            None => return,
+        };
+        if let Some(obj) = closed.as_object_mut() {
+            obj.remove(IMPLICIT);
+        }
+        match node_type(&closed) {
+            // List items, cells and quotes need at least one block.
+            "listItem" | "tableCell" | "tableHeader" | "blockquote" => {
+                let content = content_mut(&mut closed);
+                if content.is_empty() {
+                    content.push(node("paragraph"));
+                }
+            }
+            "bulletList" | "orderedList" => closed = tasks_to_task_list(closed, self),
+            _ => {}
+        }
+        content_mut(self.top()).push(closed);
+    }
+
+    fn close_implicit(&mut self) {
+        if self.top().get(IMPLICIT).is_some() {
+            self.close();
+        }
+    }
+
+    /// Adds an inline node, opening a paragraph when the current node only takes blocks.
+    fn inline(&mut self, inline: Value) {
+        if !matches!(node_type(self.top()), "paragraph" | "heading") {
+            let mut paragraph = node("paragraph");
+            paragraph[IMPLICIT] = json!(true);
+            self.stack.push(paragraph);
+        }
+        content_mut(self.top()).push(inline);
+    }
+
+    fn text(&mut self, text: &str) {
+        if let Some(code) = self.code.as_mut() {
+            code.push_str(text);
+            return;
+        }
+        if let Some((_, mention_text)) = self.mention.as_mut() {
+            mention_text.push_str(text);
+            return;
+        }
+        if text.is_empty() {
+            return;
+        }
+        let mut node = json!({ "type": "text", "text": text });
+        if !self.marks.is_empty() {
+            node["marks"] = Value::Array(self.marks.clone());
+        }
+        self.inline(node);
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) | Event::Html(text) => self.text(&text),
+            Event::Code(code) => {
+                let mut marks = vec![json!({ "type": "code" })];
+                marks.extend(
+                    self.marks
+                        .iter()
+                        .filter(|m| node_type(m) == "link")
+                        .cloned(),
+                );
+                self.inline(json!({ "type": "text", "text": code.as_ref(), "marks": marks }));
+            }
+            Event::FootnoteReference(label) => self.text(&format!("[^{label}]")),
+            // Lines typed one after the other are meant as separate lines.
+            Event::SoftBreak | Event::HardBreak => match self.code.as_mut() {
+                Some(code) => code.push('\n'),
+                None => self.inline(json!({ "type": "hardBreak" })),
+            },
+            Event::Rule => {
+                self.close_implicit();
+                content_mut(self.top()).push(json!({ "type": "rule" }));
+            }
+            Event::TaskListMarker(done) => {
+                let state = if done { "DONE" } else { "TODO" };
+                // The marker comes inside the item, or inside its paragraph for loose lists.
+                let item = self
+                    .stack
+                    .iter_mut()
+                    .rev()
+                    .find(|node| node_type(node) == "listItem");
+                if let Some(item) = item {
+                    item[TASK] = json!(state);
+                }
+            }
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.open(node("paragraph")),
+            Tag::Heading(level, _, _) => {
+                let mut heading = node("heading");
+                heading["attrs"] = json!({ "level": level as u8 });
+                self.open(heading);
+            }
+            Tag::BlockQuote => self.open(node("blockquote")),
+            Tag::CodeBlock(kind) => {
+                let mut code_block = node("codeBlock");
+                if let CodeBlockKind::Fenced(language) = kind {
+                    let language = language.split_whitespace().next().unwrap_or_default();
+                    if !language.is_empty() {
+                        code_block["attrs"] = json!({ "language": language });
+                    }
+                }
+                self.open(code_block);
+                self.code = Some(String::new());
+            }
+            Tag::List(Some(order)) => {
+                let mut list = node("orderedList");
+                list["attrs"] = json!({ "order": order });
+                self.open(list);
+            }
+            Tag::List(None) => self.open(node("bulletList")),
+            Tag::Item => self.open(node("listItem")),
+            Tag::FootnoteDefinition(label) => {
+                self.open(node("paragraph"));
+                self.text(&format!("[^{label}]: "));
+            }
+            Tag::Table(_) => {
+                let mut table = node("table");
+                table["attrs"] = json!({ "isNumberColumnEnabled": false, "layout": "default" });
+                self.open(table);
+            }
+            Tag::TableHead => {
+                self.in_table_head = true;
+                self.open(node("tableRow"));
+            }
+            Tag::TableRow => self.open(node("tableRow")),
+            Tag::TableCell => match self.in_table_head {
+                true => self.open(node("tableHeader")),
+                false => self.open(node("tableCell")),
+            },
+            Tag::Emphasis => self.marks.push(json!({ "type": "em" })),
+            Tag::Strong => self.marks.push(json!({ "type": "strong" })),
+            Tag::Strikethrough => self.marks.push(json!({ "type": "strike" })),
+            Tag::Link(_, url, _) => match url.strip_prefix("accountid:") {
+                Some(account_id) => self.mention = Some((account_id.to_string(), String::new())),
+                None => self
+                    .marks
+                    .push(json!({ "type": "link", "attrs": { "href": url.as_ref() } })),
+            },
+            // Images need an upload to become media, link to them instead.
+            Tag::Image(_, url, _) => self
+                .marks
+                .push(json!({ "type": "link", "attrs": { "href": url.as_ref() } })),
+        }
+    }
+
+    fn end(&mut self, tag: Tag) {
+        match tag {
+            Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Image(..) => {
+                self.marks.pop();
+            }
+            Tag::Link(..) => match self.mention.take() {
+                Some((id, text)) => {
+                    let text = match text.starts_with('@') {
+                        true => text,
+                        false => format!("@{text}"),
+                    };
+                    self.inline(json!({ "type": "mention", "attrs": { "id": id, "text": text } }));
+                }
+                None => {
+                    self.marks.pop();
+                }
+            },
+            Tag::CodeBlock(_) => {
+                let code = self.code.take().unwrap_or_default();
+                let code = code.trim_end_matches('\n');
+                if !code.is_empty() {
+                    content_mut(self.top()).push(json!({ "type": "text", "text": code }));
+                }
+                self.close();
+            }
+            Tag::TableHead => {
+                self.in_table_head = false;
+                self.close_implicit();
+                self.close();
+            }
+            _ => {
+                self.close_implicit();
+                self.close();
+            }
+        }
+    }
+}
+
+/// A list whose items all start with a task marker becomes an ADF task list,
+/// task items only hold inline content. Mixed lists keep the marker as text.
+fn tasks_to_task_list(mut list: Value, writer: &mut AdfWriter) -> Value {
+    let items = content_mut(&mut list);
+    let all_tasks = !items.is_empty() && items.iter().all(|item| item.get(TASK).is_some());
+    if !all_tasks {
+        for item in items.iter_mut() {
+            let state = match item.as_object_mut().and_then(|obj| obj.remove(TASK)) {
+                Some(state) => state,
+                None => continue,
+            };
+            let marker = if state == "DONE" { "[x] " } else { "[ ] " };
+            let first_block = content_mut(item).first_mut();
+            if let Some(paragraph) = first_block.filter(|b| node_type(b) == "paragraph") {
+                content_mut(paragraph).insert(0, json!({ "type": "text", "text": marker }));
+            }
+        }
+        return list;
+    }
+
+    let mut task_items = Vec::new();
+    for mut item in items.drain(..) {
+        let state = item[TASK].clone();
+        let mut inline = Vec::new();
+        for block in content_mut(&mut item).drain(..) {
+            let mut block = block;
+            if !inline.is_empty() {
+                inline.push(json!({ "type": "hardBreak" }));
+            }
+            inline.append(content_mut(&mut block));
+        }
+        let mut task = Map::new();
+        task.insert("type".to_string(), json!("taskItem"));
+        task.insert(
+            "attrs".to_string(),
+            json!({ "localId": writer.local_id(), "state": state }),
+        );
+        task.insert("content".to_string(), Value::Array(inline));
+        task_items.push(Value::Object(task));
+    }
+    json!({
+        "type": "taskList",
+        "attrs": { "localId": writer.local_id() },
+        "content": task_items,
+    })
+}
+
+/// Converts an ADF document back into the markdown `markdown_to_adf` reads, for editing.
+///
+/// Mentions become `[@name](accountid:<id>)` links. Nodes and marks markdown can't express,
+/// such as panels, media or text colours, are an error rather than being lost on the way back.
+pub fn adf_to_markdown(adf: &Value) -> anyhow::Result<String> {
+    Ok(blocks_to_markdown(children(adf))?.trim_end().to_string())
+}
+
+fn children(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(|c| c.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a Value> {
+    node.get("attrs").and_then(|attrs| attrs.get(name))
+}
+
+fn unsupported(what: &str) -> anyhow::Error {
+    anyhow::anyhow!("The comment has {what}, which can't be edited as markdown")
+}
+
+fn blocks_to_markdown(nodes: &[Value]) -> anyhow::Result<String> {
+    let blocks = nodes
+        .iter()
+        .map(block_to_markdown)
+        .collect::<anyhow::Result<Vec<String>>>()?;
+    Ok(blocks.join("\n\n"))
+}
+
+fn block_to_markdown(node: &Value) -> anyhow::Result<String> {
+    let markdown = match node_type(node) {
+        "paragraph" => inlines_to_markdown(children(node))?,
+        "heading" => {
+            let level = attr(node, "level").and_then(|l| l.as_u64()).unwrap_or(1);
+            let text = inlines_to_markdown(children(node))?;
+            format!("{} {}", "#".repeat(level.clamp(1, 6) as usize), text)
+        }
+        "blockquote" => prefix_lines(&blocks_to_markdown(children(node))?, "> ", "> "),
+        "codeBlock" => {
+            let language = attr(node, "language")
+                .and_then(|l| l.as_str())
+                .unwrap_or_default();
+            let code: String = children(node)
+                .iter()
+                .filter_map(|text| text.get("text").and_then(|t| t.as_str()))
+                .collect();
+            format!("```{}\n{}\n```", language, code)
+        }
+        "rule" => "---".to_string(),
+        "bulletList" => list_to_markdown(children(node), |_| "- ".to_string())?,
+        "orderedList" => {
+            let start = attr(node, "order").and_then(|o| o.as_u64()).unwrap_or(1);
+            list_to_markdown(children(node), |i| format!("{}. ", start + i as u64))?
+        }
+        "taskList" => {
+            let mut items = Vec::new();
+            for item in children(node) {
+                let marker = match attr(item, "state").and_then(|s| s.as_str()) {
+                    Some("DONE") => "- [x] ",
+                    _ => "- [ ] ",
+                };
+                let text = inlines_to_markdown(children(item))?;
+                items.push(prefix_lines(&text, marker, "  "));
+            }
+            items.join("\n")
+        }
+        "table" => table_to_markdown(node)?,
+        other => return Err(unsupported(&format!("a {other} block"))),
+    };
+    Ok(markdown)
+}
+
+fn list_to_markdown(items: &[Value], marker: impl Fn(usize) -> String) -> anyhow::Result<String> {
+    let mut lines = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        let mut body = String::new();
+        for (j, block) in children(item).iter().enumerate() {
+            // Nested lists follow their item's text directly, keeping the list tight
+            if j > 0 {
+                body.push_str(match node_type(block) {
+                    "bulletList" | "orderedList" | "taskList" => "\n",
+                    _ => "\n\n",
+                });
+            }
+            body.push_str(&block_to_markdown(block)?);
+        }
+        let marker = marker(i);
+        lines.push(prefix_lines(&body, &marker, &" ".repeat(marker.len())));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Pipe table, only for tables with a header row and a single paragraph per cell.
+fn table_to_markdown(table: &Value) -> anyhow::Result<String> {
+    let mut lines = Vec::new();
+    for (i, row) in children(table).iter().enumerate() {
+        let mut cells = Vec::new();
+        for cell in children(row) {
+            if i == 0 && node_type(cell) != "tableHeader" {
+                return Err(unsupported("a table without a header row"));
+            }
+            let text = match children(cell) {
+                [] => String::new(),
+                [paragraph] if node_type(paragraph) == "paragraph" => {
+                    inlines_to_markdown(children(paragraph))?.replace('\n', " ")
+                }
+                _ => return Err(unsupported("a table cell with several blocks")),
+            };
+            cells.push(text);
+        }
+        lines.push(format!("| {} |", cells.join(" | ")));
+        if i == 0 {
+            lines.push(format!("|{}|", vec!["---"; cells.len()].join("|")));
+        }
+    }
+    Ok(lines.join("\n"))
+}
+
+fn inlines_to_markdown(nodes: &[Value]) -> anyhow::Result<String> {
+    let mut markdown = String::new();
+    for node in nodes {
+        match node_type(node) {
+            "text" => markdown.push_str(&text_to_markdown(node)?),
+            "hardBreak" => markdown.push('\n'),
+            "mention" => {
+                let id = attr(node, "id")
+                    .and_then(|id| id.as_str())
+                    .unwrap_or_default();
+                let text = attr(node, "text")
+                    .and_then(|t| t.as_str())
+                    .unwrap_or_default();
+                let text = match text.starts_with('@') {
+                    true => text.to_string(),
+                    false => format!("@{text}"),
+                };
+                markdown.push_str(&format!("[{}](accountid:{})", escape_markdown(&text), id));
+            }
+            "emoji" => {
+                let emoji = attr(node, "text")
+                    .or_else(|| attr(node, "shortName"))
+                    .and_then(|e| e.as_str())
+                    .unwrap_or_default();
+                markdown.push_str(emoji);
+            }
+            "inlineCard" => {
+                let url = attr(node, "url")
+                    .and_then(|u| u.as_str())
+                    .unwrap_or_default();
+                markdown.push_str(&format!("<{}>", url));
+            }
+            other => return Err(unsupported(&format!("a {other} node"))),
+        }
+    }
+    Ok(markdown)
+}
+
+fn text_to_markdown(node: &Value) -> anyhow::Result<String> {
+    let text = node
+        .get("text")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default();
+    let marks = node
+        .get("marks")
+        .and_then(|m| m.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let mut markdown = match marks.iter().any(|m| node_type(m) == "code") {
+        true => format!("`{}`", text),
+        false => escape_markdown(text),
+    };
+    for mark in marks {
+        markdown = match node_type(mark) {
+            "code" => markdown,
+            "strong" => format!("**{}**", markdown),
+            "em" => format!("*{}*", markdown),
+            "strike" => format!("~~{}~~", markdown),
+            "link" => {
+                let href = attr(mark, "href")
+                    .and_then(|h| h.as_str())
+                    .unwrap_or_default();
+                format!("[{}]({})", markdown, href)
+            }
+            other => return Err(unsupported(&format!("{other} formatting"))),
+        };
+    }
+    Ok(markdown)
+}
+
+/// Backslash-escapes the characters markdown would read as formatting or links.
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '|' | '~') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Prefixes the first line with `first` and the others with `rest`, blank lines stay blank.
+fn prefix_lines(text: &str, first: &str, rest: &str) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let prefix = if i == 0 { first } else { rest };
+            match line.is_empty() {
+                true => prefix.trim_end().to_string(),
+                false => format!("{}{}", prefix, line),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{adf_to_markdown, escape_markdown, markdown_to_adf};
+    use crate::widgets::{adf::render_adf, comments_add::comment_markdown};
+
+    /// Renders markdown through ADF back into plain lines, the way the tickets pane shows it.
+    fn round_trip(markdown: &str) -> Vec<String> {
+        render_adf(&markdown_to_adf(markdown))
+            .lines
+            .iter()
+            .map(|line| {
+                line.0
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn code_fences_keep_indentation_and_language() {
+        let markdown = "Look:\n\n```rust\nfn main() {\n    println!(\"<b>hi</b>\");\n}\n```\n";
+        let adf = markdown_to_adf(markdown);
+        assert_eq!(adf["content"][1]["type"], "codeBlock");
+        assert_eq!(adf["content"][1]["attrs"]["language"], "rust");
+        assert_eq!(
+            round_trip(markdown),
+            vec![
+                "Look:",
+                "",
+                "┌─ rust",
+                "│ fn main() {",
+                "│     println!(\"<b>hi</b>\");",
+                "│ }",
+                "└─",
+            ]
+        );
+    }
+
+    #[test]
+    fn task_lists_become_task_items() {
+        let markdown = "- [x] write it\n- [ ] ship it\n";
+        let adf = markdown_to_adf(markdown);
+        assert_eq!(adf["content"][0]["type"], "taskList");
+        assert_eq!(adf["content"][0]["content"][0]["attrs"]["state"], "DONE");
+        assert_eq!(round_trip(markdown), vec!["[x] write it", "[ ] ship it"]);
+    }
+
+    #[test]
+    fn tables_keep_their_cells() {
+        let markdown = "| Key | Status |\n|-----|--------|\n| A-1 | Done |\n";
+        let adf = markdown_to_adf(markdown);
+        assert_eq!(
+            adf["content"][0]["content"][0]["content"][0]["type"],
+            "tableHeader"
+        );
+        assert_eq!(
+            round_trip(markdown),
+            vec![
+                "┌─────┬────────┐",
+                "│ Key │ Status │",
+                "├─────┼────────┤",
+                "│ A-1 │ Done   │",
+                "└─────┴────────┘",
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_lists_and_marks() {
+        let markdown = "1. **bold** and `code`\n   - nested [link](https://example.com)\n2. two\n";
+        assert_eq!(
+            round_trip(markdown),
+            vec![
+                "1. bold and code",
+                "   • nested link <https://example.com>",
+                "2. two",
+            ]
+        );
+    }
+
+    #[test]
+    fn mentions_and_line_breaks() {
+        let markdown = "Hi [@Ann](accountid:abc123)\nsee above";
+        let adf = markdown_to_adf(markdown);
+        let paragraph = &adf["content"][0]["content"];
+        assert_eq!(paragraph[1]["type"], "mention");
+        assert_eq!(paragraph[1]["attrs"]["id"], "abc123");
+        assert_eq!(round_trip(markdown), vec!["Hi @Ann", "see above"]);
+    }
+
+    #[test]
+    fn headings_quotes_and_rules() {
+        let markdown = "# Title\n\n> quoted\n\n---\n\nafter";
+        assert_eq!(
+            round_trip(markdown),
+            vec![
+                "Title",
+                "",
+                "│ quoted",
+                "",
+                "─".repeat(40).as_str(),
+                "",
+                "after"
+            ]
+        );
+    }
+
+    #[test]
+    fn markdown_survives_an_edit() {
+        let markdown = "# Title\n\nHi [@Ann](accountid:abc123), **bold** and `a_b`\n\n- one\n  - nested [link](https://example.com)\n- two\n\n> quoted\n\n```rust\nlet a = 1;\n```\n\n- [x] done\n- [ ] todo";
+        let edited = adf_to_markdown(&markdown_to_adf(markdown)).unwrap();
+        assert_eq!(edited, markdown);
+        assert_eq!(markdown_to_adf(&edited), markdown_to_adf(markdown));
+    }
+
+    #[test]
+    fn tables_survive_an_edit() {
+        let markdown = "| Key | Status |\n|---|---|\n| A-1 | Done |";
+        assert_eq!(
+            adf_to_markdown(&markdown_to_adf(markdown)).unwrap(),
+            markdown
+        );
+    }
+
+    #[test]
+    fn panels_cannot_be_edited() {
+        let adf = serde_json::json!({
+            "type": "doc",
+            "content": [{ "type": "panel", "content": [] }],
+        });
+        assert!(adf_to_markdown(&adf).is_err());
+    }
+
+    #[test]
+    fn typed_code_blocks_keep_their_whitespace() {
+        let lines: Vec<String> = [
+            "```rust",
+            "fn main() {",
+            "    println!(\"hi\");",
+            "}",
+            "```",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        let adf = markdown_to_adf(&comment_markdown(&lines));
+        assert_eq!(adf["content"][0]["type"], "codeBlock");
+        assert_eq!(
+            adf["content"][0]["content"][0]["text"],
+            "fn main() {\n    println!(\"hi\");\n}"
+        );
+    }
+
+    #[test]
+    fn mentions_keep_brackets_in_names() {
+        let markdown = format!(
+            "[@{}](accountid:abc123)",
+            escape_markdown("Jane Doe [Contractor]")
+        );
+        let adf = markdown_to_adf(&markdown);
+        let mention = &adf["content"][0]["content"][0];
+        assert_eq!(mention["type"], "mention");
+        assert_eq!(mention["attrs"]["id"], "abc123");
+        assert_eq!(mention["attrs"]["text"], "@Jane Doe [Contractor]");
+    }
+}
@@ -0,0 +1,175 @@
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    auth::{JiraApiError, JiraClient},
+    tickets::JiraTickets,
+    tickets::TicketData,
+};
+
+/// Whether `err` says the agile API isn't there, as on sites without Jira Software.
+pub fn is_agile_unavailable(err: &anyhow::Error) -> bool {
+    match err.downcast_ref::<JiraApiError>() {
+        Some(e) if e.status == StatusCode::NOT_FOUND => true,
+        Some(e) => e.status == StatusCode::FORBIDDEN && e.message.to_lowercase().contains("licen"),
+        None => false,
+    }
+}
+
+// The `id` alias reads Jira's ids while keeping them out of the SurrealDB record id field.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Board {
+    #[serde(alias = "id")]
+    pub board_id: u64,
+    pub name: String,
+    #[serde(alias = "type")]
+    pub board_type: String,
+    #[serde(default)]
+    pub project_key: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Sprint {
+    #[serde(alias = "id")]
+    pub sprint_id: u64,
+    pub name: String,
+    pub state: String,
+    pub goal: Option<String>,
+    #[serde(alias = "startDate")]
+    pub start_date: Option<String>,
+    #[serde(alias = "endDate")]
+    pub end_date: Option<String>,
+    #[serde(default)]
+    pub board_id: Option<u64>,
+}
+
+/// A column of the board configuration and the status ids mapped to it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BoardColumn {
+    pub name: String,
+    pub statuses: Vec<BoardColumnStatus>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BoardColumnStatus {
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct BoardConfiguration {
+    column_config: ColumnConfig,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ColumnConfig {
+    columns: Vec<BoardColumn>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AgilePage<T> {
+    #[serde(default)]
+    is_last: bool,
+    values: Vec<T>,
+}
+
+impl Board {
+    pub async fn list_for_project(
+        project_key: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<Vec<Board>> {
+        let mut boards: Vec<Board> = Vec::new();
+        loop {
+            let start_at = boards.len().to_string();
+            let params = [
+                ("projectKeyOrId", project_key),
+                ("startAt", start_at.as_str()),
+                ("maxResults", "50"),
+            ];
+            let response = jira_client
+                .get_from_agile_api_with_params("board", &params)
+                .await?;
+            let page: AgilePage<Board> = serde_json::from_str(&response)?;
+            let done = page.is_last || page.values.is_empty();
+            boards.extend(page.values);
+            if done {
+                break;
+            }
+        }
+        for board in boards.iter_mut() {
+            board.project_key = Some(project_key.to_string());
+        }
+        Ok(boards)
+    }
+
+    pub async fn columns(&self, jira_client: &JiraClient) -> anyhow::Result<Vec<BoardColumn>> {
+        let url = format!("board/{}/configuration", self.board_id);
+        let response = jira_client
+            .get_from_agile_api_with_params(&url, &[])
+            .await?;
+        let configuration: BoardConfiguration = serde_json::from_str(&response)?;
+        Ok(configuration.column_config.columns)
+    }
+
+    /// Active, future and closed sprints of the board.
+    pub async fn sprints(&self, jira_client: &JiraClient) -> anyhow::Result<Vec<Sprint>> {
+        let url = format!("board/{}/sprint", self.board_id);
+        let mut sprints: Vec<Sprint> = Vec::new();
+        loop {
+            let start_at = sprints.len().to_string();
+            let params = [
+                ("state", "active,future,closed"),
+                ("startAt", start_at.as_str()),
+                ("maxResults", "50"),
+            ];
+            let response = jira_client
+                .get_from_agile_api_with_params(&url, &params)
+                .await?;
+            let page: AgilePage<Sprint> = serde_json::from_str(&response)?;
+            let done = page.is_last || page.values.is_empty();
+            sprints.extend(page.values);
+            if done {
+                break;
+            }
+        }
+        for sprint in sprints.iter_mut() {
+            sprint.board_id = Some(self.board_id);
+        }
+        Ok(sprints)
+    }
+}
+
+impl Sprint {
+    pub async fn tickets(&self, jira_client: &JiraClient) -> anyhow::Result<Vec<TicketData>> {
+        let url = format!("sprint/{}/issue", self.sprint_id);
+        let mut tickets: Vec<TicketData> = Vec::new();
+        loop {
+            let start_at = tickets.len().to_string();
+            let params = [
+                ("startAt", start_at.as_str()),
+                ("maxResults", "50"),
+                ("expand", "renderedFields"),
+            ];
+            let response = jira_client
+                .get_from_agile_api_with_params(&url, &params)
+                .await?;
+            let page: JiraTickets = serde_json::from_str(&response)?;
+            let done = page.issues.is_empty();
+            tickets.extend(page.issues);
+            if done || tickets.len() as u32 >= page.total {
+                break;
+            }
+        }
+        Ok(tickets)
+    }
+
+    /// Sort order for the sprint list: active, then future, then closed, newest first.
+    pub fn state_rank(&self) -> u8 {
+        match self.state.as_str() {
+            "active" => 0,
+            "future" => 1,
+            _ => 2,
+        }
+    }
+}
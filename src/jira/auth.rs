@@ -1,12 +1,66 @@
+use std::{collections::HashMap, fmt};
+
+use anyhow::anyhow;
 use base64::{engine::general_purpose, Engine as _};
-use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE},
+    StatusCode,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{
+    adf::markdown_to_adf,
+    oauth::{OAuthSession, API_GATEWAY_URL},
+    wiki::markdown_to_wiki,
+};
+use crate::config::Deployment;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraErrorResponse {
+    #[serde(default)]
+    pub error_messages: Vec<String>,
+    #[serde(default)]
+    pub errors: HashMap<String, String>,
+}
+
+impl JiraErrorResponse {
+    pub fn message(&self) -> String {
+        let mut messages = self.error_messages.clone();
+        for (field, error) in &self.errors {
+            messages.push(format!("{field}: {error}"));
+        }
+        messages.join("\n")
+    }
+}
+
+/// A request Jira rejected, the status tells missing resources apart from other failures.
+#[derive(Debug)]
+pub struct JiraApiError {
+    pub status: StatusCode,
+    pub message: String,
+}
+
+impl fmt::Display for JiraApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for JiraApiError {}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct JiraClient {
+    pub deployment: Deployment,
+    pub jira_agile_url: String,
     pub jira_api_key: String,
     pub jira_api_version: String,
     pub jira_email: String,
     pub jira_url: String,
+    /// Set when logged in with OAuth, requests then carry its access token.
+    #[serde(skip)]
+    pub oauth: Option<OAuthSession>,
 }
 
 impl JiraClient {
@@ -22,74 +76,268 @@ impl JiraClient {
     //     self.jira_api_key = jira_api_key
     // }
 
-    pub fn get_basic_auth(&self) -> HeaderMap {
+    /// Headers of every request, refreshing the OAuth access token when it expired.
+    pub async fn get_basic_auth(&self) -> anyhow::Result<HeaderMap> {
         let header_content_type = HeaderValue::from_static("application/json");
-        let jira_basic_auth_str = format!("Basic {}", self.jira_api_key);
-        let mut jira_token_header = HeaderValue::from_str(&jira_basic_auth_str).unwrap();
+        let jira_basic_auth_str = match (&self.oauth, self.deployment) {
+            (Some(oauth), _) => format!("Bearer {}", oauth.access_token().await?),
+            (None, Deployment::Cloud) => format!("Basic {}", self.jira_api_key),
+            (None, Deployment::DataCenter) => format!("Bearer {}", self.jira_api_key),
+        };
+        let mut jira_token_header = HeaderValue::from_str(&jira_basic_auth_str)?;
         jira_token_header.set_sensitive(true);
         let mut headers = HeaderMap::new();
         headers.insert(CONTENT_TYPE, header_content_type.clone());
         headers.insert(ACCEPT, header_content_type);
         headers.insert(AUTHORIZATION, jira_token_header);
 
-        headers
+        Ok(headers)
+    }
+
+    pub fn is_cloud(&self) -> bool {
+        self.deployment == Deployment::Cloud
+    }
+
+    /// Rich text for a request body: an ADF document on API v3, wiki markup on v2.
+    pub fn document(&self, markdown: &str) -> Value {
+        match self.jira_api_version.as_str() {
+            "2" => Value::String(markdown_to_wiki(markdown)),
+            _ => markdown_to_adf(markdown),
+        }
+    }
+
+    /// Reference to a user in a request body, Data Center knows users by name instead of account id.
+    pub fn user_ref(&self, id: Option<&str>) -> Value {
+        match self.deployment {
+            Deployment::Cloud => json!({ "accountId": id }),
+            Deployment::DataCenter => json!({ "name": id }),
+        }
+    }
+
+    /// Query parameter searching users, Cloud's `query` is `username` on Data Center.
+    pub fn user_query_param(&self) -> &'static str {
+        match self.deployment {
+            Deployment::Cloud => "query",
+            Deployment::DataCenter => "username",
+        }
+    }
+
+    /// `url` as requests can reach it, through the API gateway when logged in with OAuth.
+    pub async fn absolute_url(&self, url: &str) -> String {
+        match &self.oauth {
+            Some(oauth) => oauth.through_gateway(url).await,
+            None => url.to_string(),
+        }
     }
 
     pub fn get_domain(&self) -> &String {
         &self.jira_url
     }
 
-    pub async fn post_to_jira_api(&self, api_url: &str, data: String) -> anyhow::Result<String> {
-        let headers = self.get_basic_auth();
-        let api_url = format!("{}/{}", self.get_domain(), api_url);
+    /// Base url of the Jira Software (agile) REST API, used for boards and sprints.
+    pub fn get_agile_domain(&self) -> &String {
+        &self.jira_agile_url
+    }
+
+    async fn get_client(&self) -> anyhow::Result<reqwest::Client> {
+        let headers = self.get_basic_auth().await?;
         let client = reqwest::Client::builder()
             .default_headers(headers)
             .https_only(true)
             .build()?;
-        let response = client.post(api_url).body(data).send().await?.text().await?;
-        Ok(response)
+        Ok(client)
+    }
+
+    /// Returns the response body, or the Jira error messages when the request was rejected.
+    async fn read_response(response: reqwest::Response) -> anyhow::Result<String> {
+        let status = response.status();
+        let body = response.text().await?;
+        if status.is_success() {
+            return Ok(body);
+        }
+        let message = match serde_json::from_str::<JiraErrorResponse>(&body) {
+            Ok(e) if !e.message().is_empty() => e.message(),
+            _ => format!("Jira responded with {status}"),
+        };
+        Err(JiraApiError { status, message }.into())
+    }
+
+    pub async fn post_to_jira_api(&self, api_url: &str, data: String) -> anyhow::Result<String> {
+        let api_url = format!("{}/{}", self.get_domain(), api_url);
+        let response = self
+            .get_client()
+            .await?
+            .post(api_url)
+            .body(data)
+            .send()
+            .await?;
+        Self::read_response(response).await
+    }
+
+    pub async fn put_to_jira_api(&self, api_url: &str, data: String) -> anyhow::Result<String> {
+        let api_url = format!("{}/{}", self.get_domain(), api_url);
+        let response = self
+            .get_client()
+            .await?
+            .put(api_url)
+            .body(data)
+            .send()
+            .await?;
+        Self::read_response(response).await
+    }
+
+    pub async fn delete_from_jira_api(&self, api_url: &str) -> anyhow::Result<String> {
+        let api_url = format!("{}/{}", self.get_domain(), api_url);
+        let response = self.get_client().await?.delete(api_url).send().await?;
+        Self::read_response(response).await
+    }
+
+    pub async fn delete_from_jira_api_with_params(
+        &self,
+        api_url: &str,
+        params: &[(&str, &str)],
+    ) -> anyhow::Result<String> {
+        let api_url = format!("{}/{}", self.get_domain(), api_url);
+        let response = self
+            .get_client()
+            .await?
+            .delete(api_url)
+            .query(params)
+            .send()
+            .await?;
+        Self::read_response(response).await
     }
 
     pub async fn get_from_jira_api(&self, api_url: &str) -> anyhow::Result<String> {
-        let headers = self.get_basic_auth();
         let api_url = format!("{}/{}", self.get_domain(), api_url);
+        let response = self.get_client().await?.get(api_url).send().await?;
+        Self::read_response(response).await
+    }
 
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
-            .https_only(true)
-            .build()?;
-        let response = client.get(api_url).send().await?.text().await?;
-        Ok(response)
+    pub async fn get_from_jira_api_with_params(
+        &self,
+        api_url: &str,
+        params: &[(&str, &str)],
+    ) -> anyhow::Result<String> {
+        let api_url = format!("{}/{}", self.get_domain(), api_url);
+        let response = self
+            .get_client()
+            .await?
+            .get(api_url)
+            .query(params)
+            .send()
+            .await?;
+        Self::read_response(response).await
+    }
+
+    /// Downloads `url` as-is, used for attachment content links which are absolute.
+    pub async fn download_from_jira(&self, url: &str) -> anyhow::Result<Vec<u8>> {
+        let url = self.absolute_url(url).await;
+        let response = self.get_client().await?.get(url).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!("Jira responded with {status}"));
+        }
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    pub async fn post_file_to_jira_api(
+        &self,
+        api_url: &str,
+        file_name: String,
+        contents: Vec<u8>,
+    ) -> anyhow::Result<String> {
+        let api_url = format!("{}/{}", self.get_domain(), api_url);
+        let part = reqwest::multipart::Part::bytes(contents).file_name(file_name);
+        let form = reqwest::multipart::Form::new().part("file", part);
+        let response = self
+            .get_client()
+            .await?
+            .post(api_url)
+            .header("X-Atlassian-Token", "no-check")
+            .multipart(form)
+            .send()
+            .await?;
+        Self::read_response(response).await
+    }
+
+    pub async fn get_from_agile_api_with_params(
+        &self,
+        api_url: &str,
+        params: &[(&str, &str)],
+    ) -> anyhow::Result<String> {
+        let api_url = format!("{}/{}", self.get_agile_domain(), api_url);
+        let response = self
+            .get_client()
+            .await?
+            .get(api_url)
+            .query(params)
+            .send()
+            .await?;
+        Self::read_response(response).await
     }
 
     pub fn new(
+        deployment: Deployment,
         jira_api_version: String,
         jira_api_key: String,
         jira_email: String,
         jira_url: String,
+        jira_agile_url: String,
     ) -> Self {
         JiraClient {
+            deployment,
+            jira_agile_url,
             jira_api_key,
             jira_api_version,
             jira_email,
             jira_url,
+            oauth: None,
         }
     }
 }
 
 pub fn jira_authentication(
+    deployment: Deployment,
     jira_domain: &str,
     jira_api_key: &str,
     jira_api_version: &str,
     jira_user_email: &str,
 ) -> JiraClient {
-    let jira_encoded_auth: String =
-        general_purpose::STANDARD_NO_PAD.encode(format!("{jira_user_email}:{jira_api_key}"));
+    // Data Center personal access tokens are sent as they are, as bearer tokens
+    let jira_encoded_auth: String = match deployment {
+        Deployment::Cloud => {
+            general_purpose::STANDARD_NO_PAD.encode(format!("{jira_user_email}:{jira_api_key}"))
+        }
+        Deployment::DataCenter => jira_api_key.to_string(),
+    };
     let jira_rest_domain = jira_domain.to_string() + "/rest/api/" + jira_api_version;
+    let jira_agile_domain = jira_domain.to_string() + "/rest/agile/1.0";
     JiraClient::new(
+        deployment,
         jira_api_version.to_string(),
         jira_encoded_auth,
         jira_user_email.to_string(),
         jira_rest_domain,
+        jira_agile_domain,
     )
 }
+
+/// Client for an OAuth login, which reaches the site through the Atlassian API gateway.
+pub async fn oauth_authentication(
+    oauth: OAuthSession,
+    jira_api_version: &str,
+    jira_user_email: &str,
+) -> JiraClient {
+    let site = format!("{}/{}", API_GATEWAY_URL, oauth.cloud_id().await);
+    let mut client = JiraClient::new(
+        Deployment::Cloud,
+        jira_api_version.to_string(),
+        String::new(),
+        jira_user_email.to_string(),
+        site.clone() + "/rest/api/" + jira_api_version,
+        site + "/rest/agile/1.0",
+    );
+    client.oauth = Some(oauth);
+    client
+}
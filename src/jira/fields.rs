@@ -0,0 +1,250 @@
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::auth::JiraClient;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldSchema {
+    #[serde(alias = "type")]
+    pub field_type: String,
+    pub items: Option<String>,
+    pub system: Option<String>,
+    pub custom: Option<String>,
+    pub custom_id: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldAllowedValue {
+    pub id: Option<String>,
+    pub key: Option<String>,
+    pub name: Option<String>,
+    pub value: Option<String>,
+}
+
+impl FieldAllowedValue {
+    pub fn label(&self) -> &str {
+        self.value
+            .as_deref()
+            .or(self.name.as_deref())
+            .or(self.key.as_deref())
+            .or(self.id.as_deref())
+            .unwrap_or_default()
+    }
+
+    fn to_value(&self) -> Value {
+        match (&self.id, &self.key) {
+            (Some(id), _) => json!({ "id": id }),
+            (None, Some(key)) => json!({ "key": key }),
+            (None, None) => json!({ "name": self.label() }),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldMeta {
+    pub field_id: Option<String>,
+    pub key: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub required: bool,
+    pub schema: FieldSchema,
+    pub allowed_values: Option<Vec<FieldAllowedValue>>,
+    pub has_default_value: Option<bool>,
+}
+
+impl FieldMeta {
+    /// The comment added with a transition, which Jira doesn't list among the screen fields.
+    pub fn comment() -> Self {
+        Self {
+            field_id: Some("comment".to_string()),
+            key: None,
+            name: "Comment".to_string(),
+            required: false,
+            schema: FieldSchema {
+                field_type: "comment".to_string(),
+                items: None,
+                system: Some("comment".to_string()),
+                custom: None,
+                custom_id: None,
+            },
+            allowed_values: None,
+            has_default_value: None,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        self.field_id
+            .as_deref()
+            .or(self.key.as_deref())
+            .unwrap_or_default()
+    }
+
+    pub fn has_allowed_values(&self) -> bool {
+        matches!(&self.allowed_values, Some(v) if !v.is_empty())
+    }
+
+    fn is_rich_text(&self) -> bool {
+        matches!(
+            self.schema.system.as_deref(),
+            Some("description") | Some("environment")
+        ) || matches!(&self.schema.custom, Some(c) if c.ends_with(":textarea"))
+    }
+
+    /// User pickers, their typed names need looking up to get account ids.
+    pub fn is_user(&self) -> bool {
+        match self.schema.field_type.as_str() {
+            "user" => true,
+            "array" => self.schema.items.as_deref() == Some("user"),
+            _ => false,
+        }
+    }
+
+    /// What to type in the field, for the types that need a particular format.
+    pub fn input_hint(&self) -> Option<&'static str> {
+        if self.has_allowed_values() {
+            return None;
+        }
+        match self.schema.field_type.as_str() {
+            "array" if self.is_user() => Some("names or emails, comma separated"),
+            "array" => Some("comma separated"),
+            "user" => Some("name or email"),
+            "date" => Some("YYYY-MM-DD"),
+            "datetime" => Some("YYYY-MM-DD HH:MM"),
+            "number" => Some("number"),
+            "timetracking" => Some("e.g. 1w 2d 3h"),
+            _ => None,
+        }
+    }
+
+    /// Builds the JSON value Jira expects for this field from the form input.
+    /// Returns `None` when nothing was entered so the field can be left out of the request,
+    /// and an error when the input doesn't fit the field's type.
+    pub fn to_value(
+        &self,
+        text: &str,
+        selected: Option<&FieldAllowedValue>,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<Option<Value>> {
+        if self.has_allowed_values() {
+            let value = match selected {
+                None => return Ok(None),
+                Some(selected) => selected.to_value(),
+            };
+            return match self.schema.field_type.as_str() {
+                "array" => Ok(Some(json!([value]))),
+                _ => Ok(Some(value)),
+            };
+        }
+
+        let text = text.trim();
+        if text.is_empty() {
+            return Ok(None);
+        }
+        let value = match self.schema.field_type.as_str() {
+            "string" if self.is_rich_text() => Some(jira_client.document(text)),
+            "comment" => Some(jira_client.document(text)),
+            "number" => match text.parse::<f64>() {
+                Ok(number) => Some(Value::from(number)),
+                Err(_) => bail!("{} must be a number, not `{}`", self.name, text),
+            },
+            "datetime" => {
+                let value = chrono::NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M")
+                    .ok()
+                    .and_then(|local| local.and_local_timezone(chrono::Local).single())
+                    .map(|local| local.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string())
+                    .unwrap_or_else(|| text.to_string());
+                Some(Value::String(value))
+            }
+            "timetracking" => Some(json!({ "remainingEstimate": text })),
+            "array" => {
+                let items: Vec<&str> = text
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|i| !i.is_empty())
+                    .collect();
+                match self.schema.items.as_deref() {
+                    Some("string") => Some(json!(items)),
+                    Some("user") => Some(Value::Array(
+                        items
+                            .iter()
+                            .map(|i| jira_client.user_ref(Some(i)))
+                            .collect(),
+                    )),
+                    _ => Some(Value::Array(
+                        items.iter().map(|i| json!({ "name": i })).collect(),
+                    )),
+                }
+            }
+            "user" => Some(jira_client.user_ref(Some(text))),
+            "issuelink" => Some(json!({ "key": text })),
+            "option" | "priority" | "resolution" | "version" | "component" => {
+                Some(json!({ "name": text }))
+            }
+            _ => Some(Value::String(text.to_string())),
+        };
+        Ok(value)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateIssueType {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub subtask: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateIssueTypes {
+    #[serde(alias = "values")]
+    pub issue_types: Vec<CreateIssueType>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateFields {
+    #[serde(alias = "values")]
+    pub fields: Vec<FieldMeta>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreatedTicket {
+    pub id: String,
+    pub key: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkCreateError {
+    pub element_errors: Value,
+    pub failed_element_number: usize,
+}
+
+impl BulkCreateError {
+    /// Jira's field errors and error messages for the failed element, on one line.
+    pub fn message(&self) -> String {
+        let mut messages: Vec<String> = Vec::new();
+        if let Some(errors) = self.element_errors["errors"].as_object() {
+            for (field, error) in errors {
+                messages.push(format!("{}: {}", field, error.as_str().unwrap_or_default()));
+            }
+        }
+        if let Some(errors) = self.element_errors["errorMessages"].as_array() {
+            messages.extend(errors.iter().filter_map(|e| e.as_str()).map(String::from));
+        }
+        messages.join(", ")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BulkCreatedTickets {
+    pub issues: Vec<CreatedTicket>,
+    #[serde(default)]
+    pub errors: Vec<BulkCreateError>,
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use super::{auth::JiraClient, tickets::CreatorReporter};
+
+// The `id` alias reads Jira's id while keeping it out of the SurrealDB record id field.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Filter {
+    #[serde(alias = "id")]
+    pub filter_id: String,
+    pub name: String,
+    pub jql: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub owner: Option<CreatorReporter>,
+}
+
+impl Filter {
+    /// Filters the user starred in Jira, shared ones included.
+    pub async fn favourites(jira_client: &JiraClient) -> anyhow::Result<Vec<Filter>> {
+        let response = jira_client.get_from_jira_api("filter/favourite").await?;
+        let obj: Vec<Filter> = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    fs,
+    io::Write,
+    path::PathBuf,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, bail};
+use log::debug;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::TcpListener,
+    sync::Mutex,
+};
+
+use crate::config::{config_dir, JiraConfigOAuth};
+
+const AUTHORIZE_URL: &str = "https://auth.atlassian.com/authorize";
+const TOKEN_URL: &str = "https://auth.atlassian.com/oauth/token";
+const RESOURCES_URL: &str = "https://api.atlassian.com/oauth/token/accessible-resources";
+/// Base url of the sites reached with an OAuth token, followed by the cloud id.
+pub const API_GATEWAY_URL: &str = "https://api.atlassian.com/ex/jira";
+pub const DEFAULT_SCOPES: &str = "read:jira-user read:jira-work write:jira-work \
+    read:board-scope:jira-software read:sprint:jira-software offline_access";
+pub const DEFAULT_REDIRECT_PORT: u16 = 8765;
+/// Access tokens are refreshed this many seconds before they expire.
+const EXPIRY_MARGIN: i64 = 60;
+
+/// Tokens saved in the config directory, so the browser login only happens once.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OAuthTokens {
+    pub access_token: String,
+    /// Only handed out with the `offline_access` scope.
+    pub refresh_token: Option<String>,
+    /// Unix timestamp, in seconds, at which the access token expires.
+    pub expires_at: i64,
+    pub cloud_id: String,
+    /// The site the tokens were issued for, a new login is needed when the domain changes.
+    pub site: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: i64,
+    refresh_token: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct AccessibleResource {
+    id: String,
+    url: String,
+}
+
+/// Logged in OAuth 2.0 (3LO) session, shared by every clone of the `JiraClient`.
+#[derive(Debug, Clone)]
+pub struct OAuthSession {
+    config: JiraConfigOAuth,
+    tokens: Arc<Mutex<OAuthTokens>>,
+}
+
+impl OAuthSession {
+    /// Reuses the saved tokens for `domain`, or logs in through the browser when there are none
+    /// or they can no longer be refreshed.
+    pub async fn login(domain: &str, config: &JiraConfigOAuth) -> anyhow::Result<Self> {
+        let site = domain.trim_end_matches('/');
+        let tokens = match load_tokens() {
+            Some(mut tokens) if tokens.site == site => match refresh(config, &mut tokens).await {
+                Ok(()) => tokens,
+                Err(e) => {
+                    debug!("The saved OAuth tokens can't be used: {e}");
+                    authorize(site, config).await?
+                }
+            },
+            _ => authorize(site, config).await?,
+        };
+        Ok(Self {
+            config: config.clone(),
+            tokens: Arc::new(Mutex::new(tokens)),
+        })
+    }
+
+    pub async fn cloud_id(&self) -> String {
+        self.tokens.lock().await.cloud_id.clone()
+    }
+
+    /// Absolute urls Jira hands out point at the site, which doesn't take OAuth tokens.
+    pub async fn through_gateway(&self, url: &str) -> String {
+        let tokens = self.tokens.lock().await;
+        match url.strip_prefix(&tokens.site) {
+            Some(path) => format!("{}/{}{}", API_GATEWAY_URL, tokens.cloud_id, path),
+            None => url.to_string(),
+        }
+    }
+
+    /// A valid access token, refreshed first when it is about to expire.
+    pub async fn access_token(&self) -> anyhow::Result<String> {
+        let mut tokens = self.tokens.lock().await;
+        refresh(&self.config, &mut tokens).await?;
+        Ok(tokens.access_token.clone())
+    }
+}
+
+/// Swaps the access token for a new one when it is about to expire.
+async fn refresh(config: &JiraConfigOAuth, tokens: &mut OAuthTokens) -> anyhow::Result<()> {
+    if tokens.expires_at - EXPIRY_MARGIN > now() {
+        return Ok(());
+    }
+    let refresh_token = tokens.refresh_token.clone().ok_or_else(|| {
+        anyhow!(
+            "The Jira login expired, delete {} and restart jirust to log in again",
+            tokens_path().display()
+        )
+    })?;
+    debug!("Refreshing the OAuth access token");
+    let response = request_tokens(json!({
+        "grant_type": "refresh_token",
+        "client_id": config.client_id,
+        "client_secret": config.client_secret,
+        "refresh_token": refresh_token,
+    }))
+    .await?;
+    tokens.access_token = response.access_token;
+    tokens.expires_at = now() + response.expires_in;
+    // Refresh tokens rotate, the previous one stops working
+    if response.refresh_token.is_some() {
+        tokens.refresh_token = response.refresh_token;
+    }
+    save_tokens(tokens)
+}
+
+/// Runs the authorization code flow: the browser redirects back to a listener on localhost.
+async fn authorize(site: &str, config: &JiraConfigOAuth) -> anyhow::Result<OAuthTokens> {
+    let port = config.redirect_port.unwrap_or(DEFAULT_REDIRECT_PORT);
+    let redirect_uri = format!("http://localhost:{port}/callback");
+    let state = random_state()?;
+    let url = Url::parse_with_params(
+        AUTHORIZE_URL,
+        &[
+            ("audience", "api.atlassian.com"),
+            ("client_id", config.client_id.as_str()),
+            ("scope", config.scopes.as_deref().unwrap_or(DEFAULT_SCOPES)),
+            ("redirect_uri", redirect_uri.as_str()),
+            ("state", state.as_str()),
+            ("response_type", "code"),
+            ("prompt", "consent"),
+        ],
+    )?;
+
+    let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+    eprintln!("Log in to Jira in your browser, or open {url}");
+    if let Err(e) = open::that(url.as_str()) {
+        debug!("Could not open the browser: {e}");
+    }
+    let code = wait_for_code(&listener, &state).await?;
+
+    let response = request_tokens(json!({
+        "grant_type": "authorization_code",
+        "client_id": config.client_id,
+        "client_secret": config.client_secret,
+        "code": code,
+        "redirect_uri": redirect_uri,
+    }))
+    .await?;
+    let cloud_id = find_cloud_id(site, &response.access_token).await?;
+    let tokens = OAuthTokens {
+        access_token: response.access_token,
+        refresh_token: response.refresh_token,
+        expires_at: now() + response.expires_in,
+        cloud_id,
+        site: site.to_string(),
+    };
+    save_tokens(&tokens)?;
+    Ok(tokens)
+}
+
+/// Answers the browser requests until the one carrying the authorization code.
+async fn wait_for_code(listener: &TcpListener, state: &str) -> anyhow::Result<String> {
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+        let mut request_line = String::new();
+        BufReader::new(&mut stream)
+            .read_line(&mut request_line)
+            .await?;
+        // GET /callback?code=...&state=... HTTP/1.1
+        let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+        let url = Url::parse(&format!("http://localhost{path}"))?;
+        if url.path() != "/callback" {
+            stream
+                .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n")
+                .await?;
+            continue;
+        }
+
+        let param = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+        let result = match (param("code"), param("error_description").or(param("error"))) {
+            (_, Some(error)) => Err(anyhow!("Jira login failed: {error}")),
+            _ if param("state").as_deref() != Some(state) => {
+                Err(anyhow!("Jira login failed: the state does not match"))
+            }
+            (Some(code), None) => Ok(code),
+            (None, None) => Err(anyhow!("Jira login failed: no authorization code")),
+        };
+        let message = match &result {
+            Ok(_) => "Logged in to Jira, you can close this tab and go back to jirust.".to_string(),
+            Err(e) => e.to_string(),
+        };
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            message.len(),
+            message
+        );
+        stream.write_all(response.as_bytes()).await?;
+        return result;
+    }
+}
+
+async fn request_tokens(body: serde_json::Value) -> anyhow::Result<TokenResponse> {
+    let client = reqwest::Client::builder().https_only(true).build()?;
+    let response = client.post(TOKEN_URL).json(&body).send().await?;
+    let status = response.status();
+    let text = response.text().await?;
+    if !status.is_success() {
+        bail!("Jira login failed with {status}: {text}");
+    }
+    Ok(serde_json::from_str(&text)?)
+}
+
+/// The cloud id of `site` among the sites the token was granted for.
+async fn find_cloud_id(site: &str, access_token: &str) -> anyhow::Result<String> {
+    let client = reqwest::Client::builder().https_only(true).build()?;
+    let resources: Vec<AccessibleResource> = client
+        .get(RESOURCES_URL)
+        .bearer_auth(access_token)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    let sites: Vec<&str> = resources.iter().map(|r| r.url.as_str()).collect();
+    resources
+        .iter()
+        .find(|r| r.url.trim_end_matches('/') == site)
+        .map(|r| r.id.clone())
+        .ok_or_else(|| {
+            anyhow!(
+                "The login was not granted access to {site}, only to: {}",
+                sites.join(", ")
+            )
+        })
+}
+
+fn tokens_path() -> PathBuf {
+    config_dir().join("oauth_tokens.json")
+}
+
+fn load_tokens() -> Option<OAuthTokens> {
+    let contents = fs::read_to_string(tokens_path()).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Writes the tokens readable by the user only.
+fn save_tokens(tokens: &OAuthTokens) -> anyhow::Result<()> {
+    let path = tokens_path();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // The mode above only applies to new files
+        if path.exists() {
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+    let mut file = options.open(&path)?;
+    file.write_all(serde_json::to_string_pretty(tokens)?.as_bytes())?;
+    Ok(())
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+/// Unguessable value tying the redirect back to this login, 128 bits from the OS generator.
+fn random_state() -> anyhow::Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes)?;
+    Ok(bytes.iter().map(|b| format!("{b:02x}")).collect())
+}
@@ -1,4 +1,7 @@
-use super::{auth::JiraClient, tickets::TicketData};
+use super::{
+    auth::JiraClient,
+    tickets::{Components, TicketData},
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -34,13 +37,14 @@ impl JiraProjects {
         &self,
         jira_auth: &JiraClient,
         url: String,
-    ) -> Result<String, reqwest::Error> {
-        let headers = jira_auth.get_basic_auth();
+    ) -> anyhow::Result<String> {
+        let headers = jira_auth.get_basic_auth().await?;
         let client = reqwest::Client::builder()
             .default_headers(headers)
             .https_only(true)
             .build()?;
-        client.get(url).send().await?.text().await
+        let url = jira_auth.absolute_url(&url).await;
+        Ok(client.get(url).send().await?.text().await?)
     }
 
     pub async fn get_projects_next_page(
@@ -69,4 +73,15 @@ impl JiraProjects {
         let obj: Project = serde_json::from_str(&response)?;
         Ok(obj)
     }
+
+    pub async fn get_project_components(
+        &self,
+        project_key: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<Vec<Components>> {
+        let url = format!("project/{}/components", project_key);
+        let response = jira_client.get_from_jira_api(&url).await?;
+        let obj: Vec<Components> = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
 }
@@ -1,8 +1,12 @@
 use std::collections::HashMap;
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
 
 use super::auth::JiraClient;
+use super::fields::{BulkCreatedTickets, CreateFields, CreateIssueTypes, CreatedTicket, FieldMeta};
+use super::users::User;
 use super::SurrealAny;
-use htmltoadf::convert_html_str_to_adf_str;
 use log::debug;
 use serde::{Deserialize, Serialize};
 
@@ -16,10 +20,18 @@ pub struct LinkFields {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LinkType {
+    pub id: Option<String>,
     pub inward: String,
+    pub name: Option<String>,
     pub outward: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLinkTypes {
+    pub issue_link_types: Vec<LinkType>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LinkInwardOutwardParent {
     pub fields: LinkFields,
@@ -31,6 +43,7 @@ pub struct LinkInwardOutwardParent {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Links {
+    pub id: Option<String>,
     pub inward_issue: Option<LinkInwardOutwardParent>,
     pub outward_issue: Option<LinkInwardOutwardParent>,
     #[serde(alias = "type")]
@@ -46,16 +59,25 @@ pub struct CreatorReporter {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Assignee {
+    #[serde(alias = "name")]
+    pub account_id: Option<String>,
     pub display_name: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RenderedFields {
+    /// Data Center sends `null` for tickets without a description.
+    #[serde(default, deserialize_with = "null_as_empty")]
     pub description: String,
 }
 
+fn null_as_empty<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Components {
+    pub id: Option<String>,
     pub name: String,
 }
 
@@ -66,7 +88,16 @@ pub struct ProjectDetails {
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Status {
+    #[serde(default)]
+    pub id: Option<String>,
     pub name: String,
+    #[serde(default, rename = "statusCategory")]
+    pub status_category: Option<StatusCategory>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StatusCategory {
+    pub key: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -83,6 +114,8 @@ pub struct Type {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct FieldAuthor {
+    #[serde(default, alias = "name")]
+    pub account_id: Option<String>,
     pub display_name: String,
     pub active: bool,
 }
@@ -90,7 +123,13 @@ pub struct FieldAuthor {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CommentBody {
+    #[serde(default)]
+    pub id: Option<String>,
     pub author: FieldAuthor,
+    /// Atlassian document format body, rendered natively instead of `rendered_body`.
+    /// API v2 sends the wiki markup string instead.
+    #[serde(default)]
+    pub body: Option<serde_json::Value>,
     pub created: String,
     pub rendered_body: String,
     pub updated: String,
@@ -108,6 +147,10 @@ pub struct Fields {
     pub comments: Option<Comments>,
     pub components: Vec<Components>,
     pub creator: Option<CreatorReporter>,
+    /// Atlassian document format description, rendered natively instead of `rendered_fields`.
+    /// API v2 sends the wiki markup string instead.
+    #[serde(default)]
+    pub description: Option<serde_json::Value>,
     pub issuetype: Type,
     pub issuelinks: Vec<Links>,
     pub labels: Vec<String>,
@@ -116,49 +159,106 @@ pub struct Fields {
     pub project: ProjectDetails,
     pub reporter: Option<CreatorReporter>,
     pub status: Status,
+    #[serde(default)]
+    pub subtasks: Vec<LinkInwardOutwardParent>,
     pub summary: String,
+    #[serde(default)]
+    pub timetracking: Option<TimeTracking>,
+    #[serde(default)]
+    pub watches: Option<Watches>,
+    #[serde(default)]
+    pub attachment: Vec<Attachment>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct TicketData {
-    pub fields: Fields,
-    pub key: String,
-    pub rendered_fields: RenderedFields,
+pub struct Attachment {
+    pub id: String,
+    pub author: Option<CreatorReporter>,
+    pub content: String,
+    pub created: String,
+    pub filename: String,
+    pub size: u64,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct CustomFieldAllowedValues {
-    pub id: String,
-    pub value: String,
+pub struct Watches {
+    pub is_watching: bool,
+    pub watch_count: u32,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct CustomFieldSchema {
-    pub custom: Option<String>,
-    pub custom_id: u32,
+pub struct Watchers {
+    pub is_watching: bool,
+    pub watchers: Vec<User>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeTracking {
+    pub original_estimate: Option<String>,
+    pub remaining_estimate: Option<String>,
+    pub time_spent: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct CustomFieldValues {
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: String,
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: String,
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    pub schema: CustomFieldSchema,
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_values: Option<Vec<CustomFieldAllowedValues>>,
+pub struct Worklog {
+    pub author: FieldAuthor,
+    pub comment: Option<serde_json::Value>,
+    pub started: String,
+    pub time_spent: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Worklogs {
+    pub worklogs: Vec<Worklog>,
+}
+
+/// One field changed in a `History` entry.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChangeItem {
+    pub field: String,
+    #[serde(rename = "fromString")]
+    pub from: Option<String>,
+    #[serde(rename = "toString")]
+    pub to: Option<String>,
+}
+
+/// A set of changes made to a ticket at once.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct History {
+    pub id: String,
+    pub author: Option<FieldAuthor>,
+    pub created: String,
+    pub items: Vec<ChangeItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ExpandedChangelog {
+    changelog: ChangelogHistories,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ChangelogHistories {
+    histories: Vec<History>,
+    total: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ChangelogPage {
+    values: Vec<History>,
+    total: u32,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct CustomField {
-    #[serde(flatten)]
-    pub values: HashMap<String, CustomFieldValues>,
+pub struct TicketData {
+    pub fields: Fields,
+    pub key: String,
+    pub rendered_fields: RenderedFields,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -167,7 +267,33 @@ pub struct TicketTransition {
     pub id: String,
     pub name: Option<String>,
     pub has_screen: Option<bool>,
-    pub fields: Option<CustomField>,
+    /// Fields of the transition screen keyed by field id, with `expand=transitions.fields`.
+    pub fields: Option<HashMap<String, FieldMeta>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<Status>,
+}
+
+impl TicketTransition {
+    /// The screen fields to fill in, required ones first, then a comment when there is a screen.
+    pub fn form_fields(&self) -> Vec<FieldMeta> {
+        let mut fields: Vec<FieldMeta> = match &self.fields {
+            None => Vec::new(),
+            Some(fields) => fields
+                .iter()
+                .map(|(id, field)| {
+                    let mut field = field.clone();
+                    field.field_id = Some(id.clone());
+                    field
+                })
+                .collect(),
+        };
+        fields.sort_by(|a, b| (!a.required, &a.name).cmp(&(!b.required, &b.name)));
+        let has_comment = fields.iter().any(|field| field.id() == "comment");
+        if self.has_screen.unwrap_or(false) && !has_comment {
+            fields.push(FieldMeta::comment());
+        }
+        fields
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -177,9 +303,51 @@ pub struct TicketTransitions {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
+pub struct TransitionId {
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PostTicketTransition {
-    pub transition: TicketTransition,
+    pub transition: TransitionId,
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub fields: serde_json::Map<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub update: serde_json::Map<String, serde_json::Value>,
+}
+
+impl PostTicketTransition {
+    pub fn new(transition_id: &str) -> Self {
+        Self {
+            transition: TransitionId {
+                id: transition_id.to_string(),
+            },
+            fields: serde_json::Map::new(),
+            update: serde_json::Map::new(),
+        }
+    }
+
+    /// Sets the screen field values, the comment goes in `update` as Jira only adds comments.
+    pub fn with_values(mut self, mut values: serde_json::Map<String, serde_json::Value>) -> Self {
+        if let Some(comment) = values.remove("comment") {
+            let add_comment = serde_json::json!([{ "add": { "body": comment } }]);
+            self.update.insert("comment".to_string(), add_comment);
+        }
+        self.fields = values;
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AutocompleteSuggestion {
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AutocompleteSuggestions {
+    pub results: Vec<AutocompleteSuggestion>,
 }
 
 impl TicketData {
@@ -219,20 +387,170 @@ impl TicketData {
         jira_client: &JiraClient,
     ) -> anyhow::Result<CommentBody> {
         let url = format!("/issue/{}/comment?expand=renderedBody", self.key);
-        let html = markdown::to_html(comment);
-        let adf = convert_html_str_to_adf_str(html);
-        let adf = format!("{{ \"body\": {} }}", adf);
-        let response = jira_client
-            .post_to_jira_api(&url, adf)
-            .await
-            .expect("unable to save comment");
-        let comments: CommentBody =
-            serde_json::from_str(response.as_str()).expect("unable to deserialize comments");
+        let adf = serde_json::json!({ "body": jira_client.document(comment) });
+        let response = jira_client.post_to_jira_api(&url, adf.to_string()).await?;
+        let comments: CommentBody = serde_json::from_str(response.as_str())?;
 
-        let _db_update: TicketData = db.update(("tickets", &self.key)).merge(&self).await?.expect("Failed to update tickets");
+        let _db_update: TicketData = db
+            .update(("tickets", &self.key))
+            .merge(&self)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Ticket {} is not in the cache", self.key))?;
         Ok(comments)
     }
 
+    pub async fn update_comment(
+        &self,
+        comment_id: &str,
+        comment: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<()> {
+        let url = format!("/issue/{}/comment/{}", self.key, comment_id);
+        let adf = serde_json::json!({ "body": jira_client.document(comment) });
+        jira_client.put_to_jira_api(&url, adf.to_string()).await?;
+        Ok(())
+    }
+
+    pub async fn delete_comment(
+        &self,
+        comment_id: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<()> {
+        let url = format!("/issue/{}/comment/{}", self.key, comment_id);
+        jira_client.delete_from_jira_api(&url).await?;
+        Ok(())
+    }
+
+    /// Sets the summary, and the description unless it is `None`.
+    pub async fn update_summary_description(
+        &self,
+        summary: &str,
+        description: Option<&str>,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<()> {
+        let url = format!("/issue/{}", self.key);
+        let mut fields = serde_json::json!({ "summary": summary });
+        if let Some(description) = description {
+            fields["description"] = jira_client.document(description);
+        }
+        let data = serde_json::json!({ "fields": fields });
+        jira_client.put_to_jira_api(&url, data.to_string()).await?;
+        Ok(())
+    }
+
+    async fn update_issue(
+        &self,
+        update: serde_json::Value,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<()> {
+        let url = format!("/issue/{}", self.key);
+        let data = serde_json::json!({ "update": update });
+        jira_client.put_to_jira_api(&url, data.to_string()).await?;
+        Ok(())
+    }
+
+    pub async fn add_label(&self, label: &str, jira_client: &JiraClient) -> anyhow::Result<()> {
+        let update = serde_json::json!({ "labels": [{ "add": label }] });
+        self.update_issue(update, jira_client).await
+    }
+
+    pub async fn remove_label(&self, label: &str, jira_client: &JiraClient) -> anyhow::Result<()> {
+        let update = serde_json::json!({ "labels": [{ "remove": label }] });
+        self.update_issue(update, jira_client).await
+    }
+
+    pub async fn add_component(
+        &self,
+        component: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<()> {
+        let update = serde_json::json!({ "components": [{ "add": { "name": component } }] });
+        self.update_issue(update, jira_client).await
+    }
+
+    pub async fn remove_component(
+        &self,
+        component: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<()> {
+        let update = serde_json::json!({ "components": [{ "remove": { "name": component } }] });
+        self.update_issue(update, jira_client).await
+    }
+
+    pub async fn get_assignable_users(
+        &self,
+        query: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<Vec<User>> {
+        User::assignable(("issueKey", self.key.as_str()), query, jira_client).await
+    }
+
+    /// Users allowed to view the ticket, the candidates for watching it.
+    pub async fn get_viewing_users(
+        &self,
+        query: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<Vec<User>> {
+        let params = [
+            ("issueKey", self.key.as_str()),
+            (jira_client.user_query_param(), query),
+            ("maxResults", "50"),
+        ];
+        let response = jira_client
+            .get_from_jira_api_with_params("/user/viewissue/search", &params)
+            .await?;
+        let obj: Vec<User> = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
+
+    pub async fn get_watchers(&self, jira_client: &JiraClient) -> anyhow::Result<Watchers> {
+        let url = format!("/issue/{}/watchers", self.key);
+        let response = jira_client.get_from_jira_api(&url).await?;
+        let obj: Watchers = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
+
+    pub async fn add_watcher(
+        &self,
+        account_id: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<()> {
+        let url = format!("/issue/{}/watchers", self.key);
+        let data = serde_json::to_string(account_id)?;
+        jira_client.post_to_jira_api(&url, data).await?;
+        Ok(())
+    }
+
+    pub async fn remove_watcher(
+        &self,
+        account_id: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<()> {
+        let param = if jira_client.is_cloud() {
+            "accountId"
+        } else {
+            "username"
+        };
+        let url = format!("/issue/{}/watchers", self.key);
+        jira_client
+            .delete_from_jira_api_with_params(&url, &[(param, account_id)])
+            .await?;
+        Ok(())
+    }
+
+    /// Assigns the ticket to `account_id`, or unassigns it when `None`.
+    /// On Data Center `account_id` is the user name.
+    pub async fn assign(
+        &self,
+        account_id: Option<&str>,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<()> {
+        let url = format!("/issue/{}/assignee", self.key);
+        let data = jira_client.user_ref(account_id);
+        jira_client.put_to_jira_api(&url, data.to_string()).await?;
+        Ok(())
+    }
+
     pub async fn get_transitions(
         &self,
         jira_client: &JiraClient,
@@ -254,6 +572,223 @@ impl TicketData {
         jira_client.post_to_jira_api(&url, data).await?;
         Ok(())
     }
+
+    /// Saves the attachment in `directory` and returns where it was written.
+    pub async fn download_attachment(
+        &self,
+        attachment: &Attachment,
+        directory: &Path,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<PathBuf> {
+        let contents = jira_client.download_from_jira(&attachment.content).await?;
+        fs::create_dir_all(directory)?;
+        // Only keep the file name so an attachment can't be written outside `directory`.
+        let file_name = Path::new(&attachment.filename)
+            .file_name()
+            .ok_or_else(|| anyhow::anyhow!("Invalid attachment name {}", attachment.filename))?;
+        // Existing files are kept, the download gets a numbered name instead: `name (1).png`
+        let name = Path::new(file_name);
+        let stem = name.file_stem().unwrap_or(file_name).to_string_lossy();
+        let extension = name
+            .extension()
+            .map(|e| format!(".{}", e.to_string_lossy()))
+            .unwrap_or_default();
+        for n in 0..1000 {
+            let path = match n {
+                0 => directory.join(file_name),
+                n => directory.join(format!("{} ({}){}", stem, n, extension)),
+            };
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(mut file) => {
+                    file.write_all(&contents)?;
+                    return Ok(path);
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Err(anyhow::anyhow!(
+            "Too many files named {} in {}",
+            attachment.filename,
+            directory.display()
+        ))
+    }
+
+    pub async fn upload_attachment(
+        &self,
+        path: &Path,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<()> {
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| anyhow::anyhow!("{} is not a file", path.display()))?
+            .to_string();
+        let contents = fs::read(path)?;
+        let url = format!("/issue/{}/attachments", self.key);
+        jira_client
+            .post_file_to_jira_api(&url, file_name, contents)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_worklogs(&self, jira_client: &JiraClient) -> anyhow::Result<Worklogs> {
+        let url = format!("/issue/{}/worklog", self.key);
+        let response = jira_client.get_from_jira_api(&url).await?;
+        let obj: Worklogs = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
+
+    /// Every change made to the ticket, newest first. `expand=changelog` only holds
+    /// the first page of histories, longer changelogs are read page by page.
+    pub async fn get_changelog(&self, jira_client: &JiraClient) -> anyhow::Result<Vec<History>> {
+        let url = format!("/issue/{}?expand=changelog&fields=summary", self.key);
+        let response = jira_client.get_from_jira_api(&url).await?;
+        let expanded: ExpandedChangelog = serde_json::from_str(&response)?;
+        let mut histories = expanded.changelog.histories;
+
+        if histories.len() < expanded.changelog.total as usize {
+            histories.clear();
+            let url = format!("/issue/{}/changelog", self.key);
+            loop {
+                let start_at = histories.len().to_string();
+                let params = [("startAt", start_at.as_str()), ("maxResults", "100")];
+                let response = jira_client
+                    .get_from_jira_api_with_params(&url, &params)
+                    .await?;
+                let page: ChangelogPage = serde_json::from_str(&response)?;
+                if page.values.is_empty() {
+                    break;
+                }
+                histories.extend(page.values);
+                if histories.len() >= page.total as usize {
+                    break;
+                }
+            }
+        }
+
+        histories.sort_by_key(|history| {
+            chrono::DateTime::parse_from_str(&history.created, "%Y-%m-%dT%H:%M:%S%.3f%z").ok()
+        });
+        histories.reverse();
+        Ok(histories)
+    }
+
+    /// Logs `time_spent`, in Jira duration syntax such as `1h 30m`, starting now.
+    pub async fn add_worklog(
+        &self,
+        time_spent: &str,
+        comment: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<Worklog> {
+        let url = format!("/issue/{}/worklog", self.key);
+        let started = chrono::Local::now()
+            .format("%Y-%m-%dT%H:%M:%S%.3f%z")
+            .to_string();
+        let mut data = serde_json::json!({ "timeSpent": time_spent, "started": started });
+        if !comment.trim().is_empty() {
+            data["comment"] = jira_client.document(comment);
+        }
+        let response = jira_client
+            .post_to_jira_api(&url, data.to_string())
+            .await?;
+        let obj: Worklog = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
+
+    /// Links this ticket to `target`. With `outward` set this ticket is the one that
+    /// "blocks"/"duplicates"/... the target, otherwise the target is.
+    pub async fn link(
+        &self,
+        link_type: &LinkType,
+        outward: bool,
+        target: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<()> {
+        let (inward_key, outward_key) = if outward {
+            (self.key.as_str(), target)
+        } else {
+            (target, self.key.as_str())
+        };
+        let link_type = match &link_type.id {
+            Some(id) => serde_json::json!({ "id": id }),
+            None => serde_json::json!({ "name": link_type.name }),
+        };
+        let data = serde_json::json!({
+            "type": link_type,
+            "inwardIssue": { "key": inward_key },
+            "outwardIssue": { "key": outward_key },
+        });
+        jira_client
+            .post_to_jira_api("/issueLink", data.to_string())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn unlink(&self, link: &Links, jira_client: &JiraClient) -> anyhow::Result<()> {
+        let id = link
+            .id
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Link has no id, refresh the ticket and retry"))?;
+        let url = format!("/issueLink/{}", id);
+        jira_client.delete_from_jira_api(&url).await?;
+        Ok(())
+    }
+}
+
+/// Plain text of an ADF document, one line per block.
+pub fn adf_to_text(adf: &serde_json::Value) -> String {
+    // API v2 sends wiki markup strings rather than documents
+    if let Some(wiki) = adf.as_str() {
+        return wiki.to_string();
+    }
+    let mut text = String::new();
+    let inline_text = adf
+        .get("text")
+        .or_else(|| adf.get("attrs").and_then(|attrs| attrs.get("text")));
+    if let Some(t) = inline_text.and_then(|t| t.as_str()) {
+        text.push_str(t);
+    }
+    if let Some(content) = adf.get("content").and_then(|c| c.as_array()) {
+        for node in content {
+            text.push_str(&adf_to_text(node));
+            let block = !matches!(
+                node.get("type").and_then(|t| t.as_str()),
+                Some("text") | Some("mention") | Some("emoji")
+            );
+            if block && !text.ends_with('\n') {
+                text.push('\n');
+            }
+        }
+    }
+    text
+}
+
+/// Whether `input` is Jira duration syntax, e.g. `1w 2d`, `1h 30m`, `1h30m` or `1.5h`.
+pub fn is_jira_duration(input: &str) -> bool {
+    let mut words = input.split_whitespace().peekable();
+    if words.peek().is_none() {
+        return false;
+    }
+    words.all(|word| {
+        // A word holds one or more `<number><unit>` tokens
+        let mut rest = word;
+        while !rest.is_empty() {
+            let unit = match rest.find(['w', 'd', 'h', 'm']) {
+                Some(unit) => unit,
+                None => return false,
+            };
+            if !rest[..unit].parse::<f64>().is_ok_and(|n| n > 0.0) {
+                return false;
+            }
+            rest = &rest[unit + 1..];
+        }
+        true
+    })
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -281,13 +816,13 @@ impl JiraTickets {
         jira_auth: &JiraClient,
         params: Vec<(&str, &str)>,
         url: &str,
-    ) -> Result<String, reqwest::Error> {
-        let headers = jira_auth.get_basic_auth();
+    ) -> anyhow::Result<String> {
+        let headers = jira_auth.get_basic_auth().await?;
         let client = reqwest::Client::builder()
             .default_headers(headers)
             .https_only(true)
             .build()?;
-        client.get(url).query(&params).send().await?.text().await
+        Ok(client.get(url).query(&params).send().await?.text().await?)
     }
 
     pub async fn search_jira_ticket_api(
@@ -300,4 +835,139 @@ impl JiraTickets {
         let obj: TicketData = serde_json::from_str(&response)?;
         Ok(obj)
     }
+
+    /// One page of the tickets matching `jql`, Jira's message is the error for invalid JQL.
+    pub async fn search_jql_page(
+        &self,
+        jql: &str,
+        start_at: u32,
+        max_results: u32,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<JiraTickets> {
+        let start_at = start_at.to_string();
+        let max_results = max_results.to_string();
+        let params = [
+            ("jql", jql),
+            ("startAt", start_at.as_str()),
+            ("maxResults", max_results.as_str()),
+            ("expand", "renderedFields"),
+        ];
+        let response = jira_client
+            .get_from_jira_api_with_params("/search", &params)
+            .await?;
+        let obj: JiraTickets = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
+
+    /// Every ticket matching `jql`, reading all the result pages.
+    pub async fn search_all_jql(
+        &self,
+        jql: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<Vec<TicketData>> {
+        let mut tickets: Vec<TicketData> = Vec::new();
+        loop {
+            let page = self
+                .search_jql_page(jql, tickets.len() as u32, 100, jira_client)
+                .await?;
+            let done = page.issues.is_empty();
+            tickets.extend(page.issues);
+            if done || tickets.len() as u32 >= page.total {
+                break;
+            }
+        }
+        Ok(tickets)
+    }
+
+    pub async fn get_label_suggestions(
+        &self,
+        prefix: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<Vec<String>> {
+        let params = [("fieldName", "labels"), ("fieldValue", prefix)];
+        let response = jira_client
+            .get_from_jira_api_with_params("/jql/autocompletedata/suggestions", &params)
+            .await?;
+        let obj: AutocompleteSuggestions = serde_json::from_str(&response)?;
+        Ok(obj.results.into_iter().map(|r| r.value).collect())
+    }
+
+    pub async fn get_link_types(&self, jira_client: &JiraClient) -> anyhow::Result<Vec<LinkType>> {
+        let response = jira_client.get_from_jira_api("/issueLinkType").await?;
+        let obj: IssueLinkTypes = serde_json::from_str(&response)?;
+        Ok(obj.issue_link_types)
+    }
+
+    pub async fn get_create_issue_types(
+        &self,
+        project_key: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<CreateIssueTypes> {
+        let url = format!("/issue/createmeta/{}/issuetypes", project_key);
+        let response = jira_client.get_from_jira_api(&url).await?;
+        let obj: CreateIssueTypes = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
+
+    pub async fn get_create_fields(
+        &self,
+        project_key: &str,
+        issue_type_id: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<CreateFields> {
+        let url = format!(
+            "/issue/createmeta/{}/issuetypes/{}?maxResults=100",
+            project_key, issue_type_id
+        );
+        let response = jira_client.get_from_jira_api(&url).await?;
+        let obj: CreateFields = serde_json::from_str(&response)?;
+        debug!("Create fields {:?}", obj);
+        Ok(obj)
+    }
+
+    pub async fn create_jira_ticket_api(
+        &self,
+        fields: serde_json::Map<String, serde_json::Value>,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<CreatedTicket> {
+        let data = serde_json::json!({ "fields": fields }).to_string();
+        let response = jira_client.post_to_jira_api("/issue", data).await?;
+        let obj: CreatedTicket = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
+
+    /// Creates a ticket per entry of `issue_fields` in one request, Jira reports failures per entry.
+    pub async fn create_jira_tickets_bulk(
+        &self,
+        issue_fields: Vec<serde_json::Value>,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<BulkCreatedTickets> {
+        let issue_updates: Vec<serde_json::Value> = issue_fields
+            .into_iter()
+            .map(|fields| serde_json::json!({ "fields": fields }))
+            .collect();
+        let data = serde_json::json!({ "issueUpdates": issue_updates }).to_string();
+        let response = jira_client.post_to_jira_api("/issue/bulk", data).await?;
+        let obj: BulkCreatedTickets = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_jira_duration;
+
+    #[test]
+    fn durations() {
+        for duration in ["1w 2d", "1h 30m", "1h30m", "2d4h 15m", "1.5h", "45m"] {
+            assert!(is_jira_duration(duration), "{duration}");
+        }
+    }
+
+    #[test]
+    fn not_durations() {
+        for input in ["", "  ", "h", "1", "1x", "1h30", "0m", "-1h", "h1", "1hh"] {
+            assert!(!is_jira_duration(input), "{input}");
+        }
+    }
 }
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use super::auth::JiraClient;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    /// Data Center has no account ids, its users are identified by their `name`.
+    #[serde(alias = "name")]
+    pub account_id: Option<String>,
+    pub active: Option<bool>,
+    pub display_name: String,
+    pub email_address: Option<String>,
+}
+
+impl User {
+    pub async fn myself(jira_client: &JiraClient) -> anyhow::Result<User> {
+        let response = jira_client.get_from_jira_api("/myself").await?;
+        let obj: User = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
+
+    /// Users matching `query` who can be assigned tickets in `scope`, which is either
+    /// `("issueKey", key)` or, for tickets not created yet, `("project", key)`.
+    pub async fn assignable(
+        scope: (&str, &str),
+        query: &str,
+        jira_client: &JiraClient,
+    ) -> anyhow::Result<Vec<User>> {
+        let params = [
+            scope,
+            (jira_client.user_query_param(), query),
+            ("maxResults", "50"),
+        ];
+        let response = jira_client
+            .get_from_jira_api_with_params("/user/assignable/search", &params)
+            .await?;
+        let obj: Vec<User> = serde_json::from_str(&response)?;
+        Ok(obj)
+    }
+}
@@ -0,0 +1,219 @@
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag};
+
+/// Converts markdown into Jira wiki markup, the rich text format of REST API v2.
+///
+/// Links to `accountid:<id>` become `[~id]` user mentions, the id being the user name
+/// on Data Center.
+pub fn markdown_to_wiki(markdown: &str) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut writer = WikiWriter::default();
+    for event in Parser::new_ext(markdown, options) {
+        writer.event(event);
+    }
+    writer.out.trim_end().to_string()
+}
+
+#[derive(Default)]
+struct WikiWriter {
+    out: String,
+    /// `*` or `#` for each list being written, outermost first.
+    lists: Vec<char>,
+    quotes: usize,
+    in_code: bool,
+    in_table_head: bool,
+    /// Text read so far of the link being written.
+    link_text: Option<String>,
+}
+
+impl WikiWriter {
+    fn write(&mut self, text: &str) {
+        match self.link_text.as_mut() {
+            Some(link_text) => link_text.push_str(text),
+            None => self.out.push_str(text),
+        }
+    }
+
+    fn line_start(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    /// Ends a block, with a blank line after it unless it sits in a list or a quote.
+    fn end_block(&mut self) {
+        self.line_start();
+        if self.lists.is_empty() && self.quotes == 0 {
+            self.out.push('\n');
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.in_code {
+            self.write(text);
+            return;
+        }
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            if matches!(c, '*' | '_' | '{' | '}' | '[' | ']' | '|') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        self.write(&escaped);
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text),
+            Event::Code(code) => self.write(&format!("{{{{{}}}}}", code)),
+            Event::Html(html) => self.write(&html),
+            Event::SoftBreak | Event::HardBreak => self.write("\n"),
+            Event::Rule => {
+                self.line_start();
+                self.out.push_str("----\n\n");
+            }
+            Event::TaskListMarker(checked) => self.write(if checked { "(/) " } else { "( ) " }),
+            Event::FootnoteReference(_) => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => {}
+            Tag::Heading(level, _, _) => {
+                self.line_start();
+                self.out.push_str(&format!("h{}. ", level as usize));
+            }
+            Tag::BlockQuote => {
+                self.line_start();
+                self.out.push_str("{quote}\n");
+                self.quotes += 1;
+            }
+            Tag::CodeBlock(kind) => {
+                self.line_start();
+                match kind {
+                    CodeBlockKind::Fenced(lang) if !lang.is_empty() => {
+                        self.out.push_str(&format!("{{code:{}}}\n", lang))
+                    }
+                    _ => self.out.push_str("{code}\n"),
+                }
+                self.in_code = true;
+            }
+            Tag::List(start) => {
+                self.line_start();
+                self.lists.push(if start.is_some() { '#' } else { '*' });
+            }
+            Tag::Item => {
+                self.line_start();
+                let bullets: String = self.lists.iter().collect();
+                self.out.push_str(&format!("{} ", bullets));
+            }
+            Tag::Table(_) => self.line_start(),
+            Tag::TableHead => {
+                self.in_table_head = true;
+                self.out.push_str("||");
+            }
+            Tag::TableRow => self.out.push('|'),
+            Tag::TableCell => {}
+            Tag::Emphasis => self.write("_"),
+            Tag::Strong => self.write("*"),
+            Tag::Strikethrough => self.write("-"),
+            Tag::Link(..) | Tag::Image(..) => self.link_text = Some(String::new()),
+            Tag::FootnoteDefinition(_) => {}
+        }
+    }
+
+    fn end(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph | Tag::Heading(..) => self.end_block(),
+            Tag::BlockQuote => {
+                self.quotes -= 1;
+                self.line_start();
+                self.out.push_str("{quote}\n");
+                self.end_block();
+            }
+            Tag::CodeBlock(_) => {
+                self.in_code = false;
+                self.line_start();
+                self.out.push_str("{code}\n");
+                self.end_block();
+            }
+            Tag::List(_) => {
+                self.lists.pop();
+                self.end_block();
+            }
+            Tag::Item => self.line_start(),
+            Tag::Table(_) => self.end_block(),
+            Tag::TableHead => {
+                self.in_table_head = false;
+                self.out.push('\n');
+            }
+            Tag::TableRow => self.out.push('\n'),
+            Tag::TableCell => self
+                .out
+                .push_str(if self.in_table_head { "||" } else { "|" }),
+            Tag::Emphasis => self.write("_"),
+            Tag::Strong => self.write("*"),
+            Tag::Strikethrough => self.write("-"),
+            Tag::Link(_, url, _) => {
+                let text = self.link_text.take().unwrap_or_default();
+                let link = match url.strip_prefix("accountid:") {
+                    Some(id) => format!("[~{}]", id),
+                    None if text.is_empty() || text == *url => format!("[{}]", url),
+                    None => format!("[{}|{}]", text, url),
+                };
+                self.write(&link);
+            }
+            Tag::Image(_, url, _) => {
+                self.link_text = None;
+                self.write(&format!("!{}!", url));
+            }
+            Tag::FootnoteDefinition(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::markdown_to_wiki;
+
+    #[test]
+    fn marks_headings_and_links() {
+        let markdown =
+            "# Title\n\nSome **bold**, *italic* and `code` with a [link](https://example.com).";
+        assert_eq!(
+            markdown_to_wiki(markdown),
+            "h1. Title\n\nSome *bold*, _italic_ and {{code}} with a [link|https://example.com]."
+        );
+    }
+
+    #[test]
+    fn nested_lists() {
+        let markdown = "* one\n  1. first\n  2. second\n* two\n";
+        assert_eq!(
+            markdown_to_wiki(markdown),
+            "* one\n*# first\n*# second\n* two"
+        );
+    }
+
+    #[test]
+    fn code_blocks_keep_their_text() {
+        let markdown = "```rust\nlet a = [1, 2];\n```\n";
+        assert_eq!(
+            markdown_to_wiki(markdown),
+            "{code:rust}\nlet a = [1, 2];\n{code}"
+        );
+    }
+
+    #[test]
+    fn tables_and_mentions() {
+        let markdown = "| a | b |\n|---|---|\n| 1 | 2 |\n\nThanks [@Jane](accountid:jdoe)";
+        assert_eq!(
+            markdown_to_wiki(markdown),
+            "||a||b||\n|1|2|\n\nThanks [~jdoe]"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 mod app;
 mod config;
+mod editor;
 mod event;
 mod jira;
 mod widgets;
@@ -8,21 +9,36 @@ mod widgets;
 
 use crate::event::event::Event;
 use app::App;
+use config::KeyConfig;
 use crossterm::{
     cursor,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
+use jira::oauth::OAuthSession;
 // use log::{debug, error, info, log_enabled, Level};
 // use serde::{Deserialize, Serialize};
 use std::io;
-use tui::{backend::CrosstermBackend, Terminal};
+use tui::{backend::CrosstermBackend, layout::Rect, Terminal};
+use widgets::{error::ErrorComponent, DrawableComponent};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
-    let config = config::Config::new().unwrap();
+    let config = match config::Config::new() {
+        Ok(config) => config,
+        Err(err) => return startup_error(err),
+    };
+
+    // The browser login happens before the terminal is taken over
+    let oauth = match &config.jira_config.oauth {
+        Some(oauth) => match OAuthSession::login(&config.jira_config.domain, oauth).await {
+            Ok(session) => Some(session),
+            Err(err) => return startup_error(err),
+        },
+        None => None,
+    };
 
     setup_terminal()?;
 
@@ -33,7 +49,10 @@ async fn main() -> anyhow::Result<()> {
         original_hook(panic);
     }));
 
-    let mut app: App = App::new(config.clone()).await?;
+    let mut app: App = match App::new(config.clone(), oauth).await {
+        Ok(app) => app,
+        Err(err) => return startup_error(err),
+    };
     let stdout = io::stdout();
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
@@ -49,6 +68,15 @@ async fn main() -> anyhow::Result<()> {
                 std::process::exit(1);
             }
         })?;
+
+        // Bulk operations go one ticket per loop so the progress is drawn in between
+        if app.bulk_running() {
+            if let Err(err) = app.bulk_step().await {
+                app.error.set(err.to_string())?;
+            }
+            continue;
+        }
+
         match events.next()? {
             Event::Input(key) => match app.event(key).await {
                 Ok(state) => {
@@ -61,7 +89,28 @@ async fn main() -> anyhow::Result<()> {
                 Err(err) => app.error.set(err.to_string())?,
                 // Err(_err) => {}
             },
-            Event::Tick => (),
+            Event::Tick => {
+                if let Err(err) = app.tick().await {
+                    app.error.set(err.to_string())?;
+                }
+            }
+        }
+
+        if let Some(request) = app.editor_request.take() {
+            events.pause();
+            shutdown_terminal();
+            let edited = request.open();
+            setup_terminal()?;
+            terminal.clear()?;
+            events.resume();
+
+            let result = match edited {
+                Ok(()) => app.editor_closed(request).await,
+                Err(err) => Err(err),
+            };
+            if let Err(err) = result {
+                app.error.set(err.to_string())?;
+            }
         }
     }
 
@@ -70,6 +119,26 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Shows why jirust could not start until a key is pressed, then exits.
+fn startup_error(err: anyhow::Error) -> anyhow::Result<()> {
+    setup_terminal()?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    let mut error = ErrorComponent::new(KeyConfig::default());
+    error.set(format!("{err:#}\n\nPress any key to quit."))?;
+    terminal.clear()?;
+    terminal.draw(|f| {
+        if let Err(err) = error.draw(f, Rect::default(), false) {
+            eprintln!("Error: {err:?}");
+        }
+    })?;
+
+    let events = event::event::Events::new(250);
+    while !matches!(events.next()?, Event::Input(_)) {}
+    shutdown_terminal();
+    eprintln!("Error: {err:#}");
+    std::process::exit(1);
+}
+
 fn setup_terminal() -> anyhow::Result<()> {
     enable_raw_mode()?;
     io::stdout().execute(EnterAlternateScreen)?;
@@ -1,17 +1,36 @@
+pub mod adf;
+pub mod attachments;
+pub mod autocomplete;
+pub mod bulk;
 pub mod commands;
 pub mod comments;
 pub mod comments_add;
 pub mod components;
+pub mod confirm;
 pub mod error;
+pub mod field_form;
+pub mod filters;
 pub mod help;
+pub mod history;
+pub mod kanban;
 pub mod labels;
 pub mod parent;
 pub mod projects;
 pub mod search_projects;
 pub mod search_tickets;
+pub mod sprints;
+pub mod subtask_add;
+pub mod subtasks;
+pub mod ticket_create;
+pub mod ticket_link;
 pub mod ticket_relation;
 pub mod ticket_transition;
+pub mod ticket_tree;
 pub mod tickets;
+pub mod user_picker;
+pub mod watchers;
+pub mod worklog_add;
+pub mod worklogs;
 
 use commands::CommandInfo;
 
@@ -56,6 +75,7 @@ pub trait DrawableComponent {
     fn draw<B: Backend>(&self, f: &mut Frame<B>, rect: Rect, focused: bool) -> anyhow::Result<()>;
 }
 
+#[allow(dead_code)]
 pub trait StatefulDrawableComponent {
     fn draw<B: Backend>(
         &mut self,
@@ -65,6 +85,7 @@ pub trait StatefulDrawableComponent {
     ) -> anyhow::Result<()>;
 }
 
+#[allow(dead_code)]
 pub trait MovableComponent {
     fn draw<B: Backend>(
         &mut self,
@@ -77,6 +98,7 @@ pub trait MovableComponent {
 }
 
 /// base component trait
+#[allow(dead_code)]
 #[async_trait]
 pub trait Component {
     fn commands(&self, out: &mut Vec<CommandInfo>);
@@ -109,7 +131,7 @@ pub trait Component {
     }
 }
 
-pub fn draw_block_style(focused: bool, title: &str) -> Block {
+pub fn draw_block_style(focused: bool, title: &str) -> Block<'_> {
     if focused {
         Block::default()
             .border_type(BorderType::Double)
@@ -0,0 +1,395 @@
+use serde_json::Value;
+use tui::{
+    style::{Color, Modifier, Style},
+    text::{Span, Spans, Text},
+};
+
+/// Renders an Atlassian document format document into styled text.
+pub fn render_adf(doc: &Value) -> Text<'static> {
+    Text::from(blocks(children(doc), true))
+}
+
+fn children(node: &Value) -> &[Value] {
+    match node.get("content").and_then(|c| c.as_array()) {
+        Some(content) => content.as_slice(),
+        None => &[],
+    }
+}
+
+fn node_type(node: &Value) -> &str {
+    node.get("type")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default()
+}
+
+fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a Value> {
+    node.get("attrs").and_then(|attrs| attrs.get(name))
+}
+
+fn attr_str<'a>(node: &'a Value, name: &str) -> Option<&'a str> {
+    attr(node, name).and_then(|a| a.as_str())
+}
+
+fn width(line: &Spans) -> usize {
+    line.0.iter().map(|span| span.content.chars().count()).sum()
+}
+
+/// Block nodes one after the other, `spaced` puts an empty line between them.
+fn blocks(nodes: &[Value], spaced: bool) -> Vec<Spans<'static>> {
+    let mut lines = Vec::new();
+    for node in nodes {
+        let block = block(node);
+        if block.is_empty() {
+            continue;
+        }
+        if spaced && !lines.is_empty() {
+            lines.push(Spans::default());
+        }
+        lines.extend(block);
+    }
+    lines
+}
+
+fn block(node: &Value) -> Vec<Spans<'static>> {
+    match node_type(node) {
+        "paragraph" => inline(children(node), Style::default()),
+        "heading" => {
+            let level = attr(node, "level").and_then(|l| l.as_u64()).unwrap_or(1);
+            let mut style = Style::default().add_modifier(Modifier::BOLD);
+            if level <= 2 {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+            inline(children(node), style)
+        }
+        "bulletList" => list(node, |_| "• ".to_string()),
+        "orderedList" => {
+            let start = attr(node, "order").and_then(|o| o.as_u64()).unwrap_or(1);
+            let last = start + children(node).len().saturating_sub(1) as u64;
+            let digits = last.to_string().len();
+            list(node, move |i| {
+                format!("{:>digits$}. ", start + i as u64, digits = digits)
+            })
+        }
+        "taskList" | "decisionList" => blocks(children(node), false),
+        "taskItem" => {
+            let marker = match attr_str(node, "state") {
+                Some("DONE") => "[x] ",
+                _ => "[ ] ",
+            };
+            prefixed(inline(children(node), Style::default()), marker)
+        }
+        "decisionItem" => prefixed(inline(children(node), Style::default()), "» "),
+        "codeBlock" => code_block(node),
+        "blockquote" => bordered(
+            blocks(children(node), true),
+            Style::default().fg(Color::DarkGray),
+        ),
+        "panel" => panel(node),
+        "rule" => vec![Spans::from(Span::styled(
+            "─".repeat(40),
+            Style::default().fg(Color::DarkGray),
+        ))],
+        "table" => table(node),
+        "mediaSingle" | "mediaGroup" => blocks(children(node), false),
+        "media" => {
+            let name = attr_str(node, "alt").unwrap_or("attachment");
+            vec![Spans::from(Span::styled(
+                format!("[{name}]"),
+                Style::default().fg(Color::DarkGray),
+            ))]
+        }
+        "expand" | "nestedExpand" => {
+            let title = attr_str(node, "title").unwrap_or_default();
+            let mut lines = vec![Spans::from(Span::styled(
+                format!("▾ {title}"),
+                Style::default().add_modifier(Modifier::BOLD),
+            ))];
+            lines.extend(prefixed(blocks(children(node), true), "  "));
+            lines
+        }
+        // Inline nodes or unknown blocks, render whatever text they hold.
+        _ if node.get("content").is_some() => blocks(children(node), true),
+        _ => inline(std::slice::from_ref(node), Style::default()),
+    }
+}
+
+/// Prefixes the first line with `marker` and indents the others to line up with it.
+fn prefixed(lines: Vec<Spans<'static>>, marker: &str) -> Vec<Spans<'static>> {
+    let indent = " ".repeat(marker.chars().count());
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut line)| {
+            let prefix = if i == 0 { marker } else { indent.as_str() };
+            line.0.insert(0, Span::raw(prefix.to_string()));
+            line
+        })
+        .collect()
+}
+
+fn list(node: &Value, marker: impl Fn(usize) -> String) -> Vec<Spans<'static>> {
+    let mut lines = Vec::new();
+    for (i, item) in children(node).iter().enumerate() {
+        let mut item_lines = blocks(children(item), false);
+        if item_lines.is_empty() {
+            item_lines.push(Spans::default());
+        }
+        lines.extend(prefixed(item_lines, &marker(i)));
+    }
+    lines
+}
+
+fn bordered(lines: Vec<Spans<'static>>, style: Style) -> Vec<Spans<'static>> {
+    lines
+        .into_iter()
+        .map(|mut line| {
+            line.0.insert(0, Span::styled("│ ", style));
+            line
+        })
+        .collect()
+}
+
+fn code_block(node: &Value) -> Vec<Spans<'static>> {
+    let border = Style::default().fg(Color::DarkGray);
+    let code_style = Style::default().fg(Color::Yellow);
+    let language = attr_str(node, "language").unwrap_or_default();
+    let code: String = children(node)
+        .iter()
+        .filter_map(|text| text.get("text").and_then(|t| t.as_str()))
+        .collect();
+    let mut lines = vec![Spans::from(Span::styled(format!("┌─ {language}"), border))];
+    lines.extend(code.lines().map(|line| {
+        Spans::from(vec![
+            Span::styled("│ ", border),
+            Span::styled(line.to_string(), code_style),
+        ])
+    }));
+    lines.push(Spans::from(Span::styled("└─", border)));
+    lines
+}
+
+fn panel(node: &Value) -> Vec<Spans<'static>> {
+    let (label, color) = match attr_str(node, "panelType") {
+        Some("note") => ("Note", Color::Magenta),
+        Some("warning") => ("Warning", Color::Yellow),
+        Some("success") => ("Success", Color::Green),
+        Some("error") => ("Error", Color::Red),
+        _ => ("Info", Color::Blue),
+    };
+    let style = Style::default().fg(color);
+    let mut lines = vec![Spans::from(Span::styled(
+        label,
+        style.add_modifier(Modifier::BOLD),
+    ))];
+    lines.extend(blocks(children(node), true));
+    lines
+        .into_iter()
+        .map(|mut line| {
+            line.0.insert(0, Span::styled("┃ ", style));
+            line
+        })
+        .collect()
+}
+
+fn table(node: &Value) -> Vec<Spans<'static>> {
+    let border = Style::default().fg(Color::DarkGray);
+    // Every cell is a list of lines, header cells are bold.
+    let rows: Vec<Vec<(bool, Vec<Spans<'static>>)>> = children(node)
+        .iter()
+        .map(|row| {
+            children(row)
+                .iter()
+                .map(|cell| {
+                    let header = node_type(cell) == "tableHeader";
+                    let mut lines = blocks(children(cell), false);
+                    if header {
+                        for line in lines.iter_mut() {
+                            for span in line.0.iter_mut() {
+                                span.style = span.style.add_modifier(Modifier::BOLD);
+                            }
+                        }
+                    }
+                    (header, lines)
+                })
+                .collect()
+        })
+        .collect();
+    let columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    if columns == 0 {
+        return Vec::new();
+    }
+    let mut widths = vec![0; columns];
+    for row in &rows {
+        for (i, (_, lines)) in row.iter().enumerate() {
+            let cell_width = lines.iter().map(width).max().unwrap_or(0);
+            widths[i] = widths[i].max(cell_width);
+        }
+    }
+
+    let separator = |left: &str, middle: &str, right: &str| {
+        let bars: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+        Spans::from(Span::styled(
+            format!("{left}{}{right}", bars.join(middle)),
+            border,
+        ))
+    };
+    let mut lines = vec![separator("┌", "┬", "┐")];
+    for (r, row) in rows.iter().enumerate() {
+        let height = row.iter().map(|(_, l)| l.len()).max().unwrap_or(0).max(1);
+        for l in 0..height {
+            let mut spans = vec![Span::styled("│", border)];
+            for (i, column_width) in widths.iter().enumerate() {
+                let cell_line = row.get(i).and_then(|(_, lines)| lines.get(l));
+                let used = cell_line.map(width).unwrap_or(0);
+                spans.push(Span::raw(" "));
+                if let Some(cell_line) = cell_line {
+                    spans.extend(cell_line.0.iter().cloned());
+                }
+                spans.push(Span::raw(" ".repeat(column_width - used + 1)));
+                spans.push(Span::styled("│", border));
+            }
+            lines.push(Spans::from(spans));
+        }
+        let header_row = row.iter().all(|(header, _)| *header);
+        if r + 1 < rows.len() && header_row {
+            lines.push(separator("├", "┼", "┤"));
+        }
+    }
+    lines.push(separator("└", "┴", "┘"));
+    lines
+}
+
+/// Inline nodes as lines, a new line starts at every hard break or raw new line.
+fn inline(nodes: &[Value], base: Style) -> Vec<Spans<'static>> {
+    let mut lines = vec![Vec::new()];
+    for node in nodes {
+        match node_type(node) {
+            "hardBreak" => lines.push(Vec::new()),
+            "text" => {
+                let text = node
+                    .get("text")
+                    .and_then(|t| t.as_str())
+                    .unwrap_or_default();
+                let (style, href) = marks(node, base);
+                for (i, part) in text.split('\n').enumerate() {
+                    if i > 0 {
+                        lines.push(Vec::new());
+                    }
+                    let current = lines.last_mut().expect("there is always a line");
+                    current.push(Span::styled(part.to_string(), style));
+                }
+                if let Some(href) = href.filter(|href| href != text) {
+                    let current = lines.last_mut().expect("there is always a line");
+                    current.push(Span::styled(
+                        format!(" <{href}>"),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+            }
+            _ => {
+                if let Some(span) = inline_node(node, base) {
+                    lines.last_mut().expect("there is always a line").push(span);
+                }
+            }
+        }
+    }
+    lines.into_iter().map(Spans::from).collect()
+}
+
+fn inline_node(node: &Value, base: Style) -> Option<Span<'static>> {
+    let span = match node_type(node) {
+        "mention" => {
+            let text = attr_str(node, "text").unwrap_or_default();
+            let text = match text.starts_with('@') {
+                true => text.to_string(),
+                false => format!("@{text}"),
+            };
+            Span::styled(text, base.fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        }
+        "emoji" => {
+            let text = attr_str(node, "text").or_else(|| attr_str(node, "shortName"))?;
+            Span::styled(text.to_string(), base)
+        }
+        "status" => {
+            let text = attr_str(node, "text").unwrap_or_default().to_uppercase();
+            let color = match attr_str(node, "color") {
+                Some("purple") => Color::Magenta,
+                Some("blue") => Color::Blue,
+                Some("red") => Color::Red,
+                Some("yellow") => Color::Yellow,
+                Some("green") => Color::Green,
+                _ => Color::Gray,
+            };
+            Span::styled(
+                format!(" {text} "),
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(color)
+                    .add_modifier(Modifier::BOLD),
+            )
+        }
+        "date" => {
+            let timestamp = attr(node, "timestamp").and_then(|t| match t {
+                Value::String(s) => s.parse::<i64>().ok(),
+                other => other.as_i64(),
+            })?;
+            let date = chrono::NaiveDateTime::from_timestamp_millis(timestamp)?;
+            Span::styled(date.format("%Y-%m-%d").to_string(), base.fg(Color::Cyan))
+        }
+        "inlineCard" | "blockCard" => {
+            let url = attr_str(node, "url")?;
+            Span::styled(url.to_string(), link_style(base))
+        }
+        "placeholder" => {
+            let text = attr_str(node, "text")?;
+            Span::styled(text.to_string(), base.fg(Color::DarkGray))
+        }
+        _ => {
+            let text = node
+                .get("text")
+                .or_else(|| attr(node, "text"))
+                .and_then(|t| t.as_str())?;
+            Span::styled(text.to_string(), base)
+        }
+    };
+    Some(span)
+}
+
+fn link_style(base: Style) -> Style {
+    base.fg(Color::Blue).add_modifier(Modifier::UNDERLINED)
+}
+
+/// Style of a text node and the target of its link mark, if any.
+fn marks(node: &Value, base: Style) -> (Style, Option<String>) {
+    let mut style = base;
+    let mut href = None;
+    let marks = node.get("marks").and_then(|m| m.as_array());
+    for mark in marks.into_iter().flatten() {
+        style = match node_type(mark) {
+            "strong" => style.add_modifier(Modifier::BOLD),
+            "em" => style.add_modifier(Modifier::ITALIC),
+            "underline" => style.add_modifier(Modifier::UNDERLINED),
+            "strike" => style.add_modifier(Modifier::CROSSED_OUT),
+            "code" => style.fg(Color::Yellow).bg(Color::Black),
+            "link" => {
+                href = attr_str(mark, "href").map(str::to_string);
+                link_style(style)
+            }
+            "textColor" => match attr_str(mark, "color").and_then(hex_color) {
+                Some(color) => style.fg(color),
+                None => style,
+            },
+            _ => style,
+        };
+    }
+    (style, href)
+}
+
+fn hex_color(hex: &str) -> Option<Color> {
+    let hex = hex.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?))
+}
@@ -0,0 +1,189 @@
+use std::{fs, path::PathBuf};
+
+use chrono::DateTime;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    config::{expand_home, KeyConfig},
+    event::key::Key,
+    jira::tickets::{Attachment, TicketData},
+};
+
+use super::{draw_block_style, draw_highlight_style, EventState};
+
+pub struct AttachmentsWidget {
+    attachments: Vec<Attachment>,
+    key_config: KeyConfig,
+    state: TableState,
+    title: String,
+}
+
+/// Entries of the directory `input` points into, written the way `input` was typed.
+/// Directories end with `/` so completing them keeps descending.
+pub fn path_completions(input: &str) -> Vec<String> {
+    let typed_dir = match input.rfind('/') {
+        Some(i) => &input[..=i],
+        None => "",
+    };
+    let dir = match typed_dir {
+        "" => PathBuf::from("."),
+        d => expand_home(d),
+    };
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    let mut completions: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if entry.path().is_dir() {
+                format!("{}{}/", typed_dir, name)
+            } else {
+                format!("{}{}", typed_dir, name)
+            }
+        })
+        .collect();
+    completions.sort();
+    completions
+}
+
+fn human_size(size: u64) -> String {
+    let units = ["B", "KB", "MB", "GB"];
+    let mut size = size as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} {}", size, units[unit]),
+        _ => format!("{:.1} {}", size, units[unit]),
+    }
+}
+
+impl AttachmentsWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>, focused: bool) -> anyhow::Result<()> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([Constraint::Length(1), Constraint::Min(1)].as_ref())
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let msg = vec![
+            Span::raw("Press "),
+            Span::styled("ESC", bold),
+            Span::raw(" to exit, "),
+            Span::styled(self.key_config.enter.to_string(), bold),
+            Span::raw(" to download, "),
+            Span::styled(self.key_config.open_browser.to_string(), bold),
+            Span::raw(" to download and open, "),
+            Span::styled(self.key_config.add.to_string(), bold),
+            Span::raw(" to upload a file."),
+        ];
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(Style::default().add_modifier(Modifier::UNDERLINED));
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        let headers = Row::new(["Filename", "Size", "Author", "Date"]);
+        let rows = self.attachments.iter().map(|attachment| {
+            let author = match &attachment.author {
+                Some(a) => a.display_name.clone(),
+                None => String::new(),
+            };
+            let created = DateTime::parse_from_str(&attachment.created, "%Y-%m-%dT%H:%M:%S%.3f%z")
+                .map(|created| created.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_else(|_| attachment.created.clone());
+            Row::new([
+                Cell::from(attachment.filename.clone()),
+                Cell::from(human_size(attachment.size)),
+                Cell::from(author),
+                Cell::from(created),
+            ])
+        });
+        let table = Table::new(rows)
+            .header(headers)
+            .block(draw_block_style(focused, &self.title))
+            .highlight_style(draw_highlight_style())
+            .widths(&[
+                Constraint::Percentage(45),
+                Constraint::Percentage(10),
+                Constraint::Percentage(25),
+                Constraint::Percentage(20),
+            ]);
+        f.render_stateful_widget(table, chunks[1], &mut self.state);
+
+        Ok(())
+    }
+}
+
+impl AttachmentsWidget {
+    pub fn new(key_config: KeyConfig) -> Self {
+        Self {
+            attachments: Vec::new(),
+            key_config,
+            state: TableState::default(),
+            title: String::new(),
+        }
+    }
+
+    pub fn update(&mut self, ticket: &TicketData) {
+        self.title = format!("{} attachments", ticket.key);
+        self.attachments = ticket.fields.attachment.clone();
+        let selected = match self.state.selected() {
+            _ if self.attachments.is_empty() => None,
+            Some(i) => Some(i.min(self.attachments.len() - 1)),
+            None => Some(0),
+        };
+        self.state.select(selected);
+    }
+
+    pub fn selected(&self) -> Option<&Attachment> {
+        match self.state.selected() {
+            Some(i) => self.attachments.get(i),
+            None => None,
+        }
+    }
+
+    pub fn next(&mut self, line: usize) {
+        if self.attachments.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.attachments.len() - 1));
+        self.state.select(i);
+    }
+
+    pub fn previous(&mut self, line: usize) {
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
+        self.state.select(i);
+    }
+}
+
+impl AttachmentsWidget {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if key == self.key_config.scroll_down || key == self.key_config.move_down {
+            self.next(1);
+        } else if key == self.key_config.scroll_up || key == self.key_config.move_up {
+            self.previous(1);
+        } else if key == self.key_config.scroll_down_multiple_lines {
+            self.next(10);
+        } else if key == self.key_config.scroll_up_multiple_lines {
+            self.previous(10);
+        } else {
+            return Ok(EventState::NotConsumed);
+        }
+        Ok(EventState::Consumed)
+    }
+}
@@ -0,0 +1,185 @@
+use simsearch::SimSearch;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::event::key::Key;
+
+use super::{draw_block_style, draw_highlight_style, EventState};
+
+/// Single line input with a list of completions filtered by `SimSearch`, or by prefix for paths.
+/// Nothing is highlighted until the user moves into the list, so `Enter` submits the typed text.
+pub struct AutocompleteWidget {
+    candidates: Vec<String>,
+    filtered: Vec<usize>,
+    fuzzy: bool,
+    state: ListState,
+    title: String,
+    pub input: String,
+    pub input_changed: bool,
+    pub submitted: Option<String>,
+}
+
+impl AutocompleteWidget {
+    pub fn new() -> Self {
+        Self {
+            candidates: Vec::new(),
+            filtered: Vec::new(),
+            fuzzy: true,
+            input: String::new(),
+            input_changed: false,
+            state: ListState::default(),
+            submitted: None,
+            title: String::new(),
+        }
+    }
+
+    pub fn open(&mut self, title: &str, candidates: Vec<String>) {
+        self.title = title.to_string();
+        self.fuzzy = true;
+        self.input.clear();
+        self.input_changed = false;
+        self.submitted = None;
+        self.update(candidates);
+    }
+
+    /// Like `open`, but only keeps candidates starting with the input, as a shell completes paths.
+    pub fn open_prefix(&mut self, title: &str, input: &str, candidates: Vec<String>) {
+        self.open(title, Vec::new());
+        self.fuzzy = false;
+        self.input = input.to_string();
+        self.update(candidates);
+    }
+
+    pub fn set_input(&mut self, input: &str) {
+        self.input = input.to_string();
+        self.filter();
+    }
+
+    pub fn update(&mut self, candidates: Vec<String>) {
+        self.candidates = candidates;
+        self.filter();
+    }
+
+    fn filter(&mut self) {
+        self.state.select(None);
+        if self.input.is_empty() {
+            self.filtered = (0..self.candidates.len()).collect();
+            return;
+        }
+        if !self.fuzzy {
+            self.filtered = (0..self.candidates.len())
+                .filter(|index| self.candidates[*index].starts_with(&self.input))
+                .collect();
+            return;
+        }
+        let mut engine: SimSearch<usize> = SimSearch::new();
+        for (index, candidate) in self.candidates.iter().enumerate() {
+            engine.insert(index, candidate);
+        }
+        self.filtered = engine.search(&self.input);
+    }
+
+    pub fn selected(&self) -> Option<&String> {
+        match self.state.selected() {
+            Some(i) => self
+                .filtered
+                .get(i)
+                .and_then(|index| self.candidates.get(*index)),
+            None => None,
+        }
+    }
+
+    pub fn next(&mut self, line: usize) {
+        if self.filtered.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) => (i + line).min(self.filtered.len() - 1),
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self, line: usize) {
+        let i = match self.state.selected() {
+            Some(0) | None => None,
+            Some(i) => Some(i.saturating_sub(line)),
+        };
+        self.state.select(i);
+    }
+}
+
+impl AutocompleteWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let width = 80;
+        let height = 20;
+        let area = Rect::new(
+            (f.size().width.saturating_sub(width)) / 2,
+            (f.size().height.saturating_sub(height)) / 2,
+            width.min(f.size().width),
+            height.min(f.size().height),
+        );
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
+            .split(area);
+        f.render_widget(Clear, area);
+
+        let input = Paragraph::new(self.input.as_ref())
+            .style(Style::default().fg(Color::Yellow))
+            .block(draw_block_style(true, &self.title));
+        f.render_widget(input, chunks[0]);
+
+        let list_items: Vec<ListItem> = self
+            .filtered
+            .iter()
+            .filter_map(|index| self.candidates.get(*index))
+            .map(|candidate| ListItem::new(candidate.as_str()))
+            .collect();
+        let list = List::new(list_items)
+            .block(Block::default().borders(Borders::ALL).title("Suggestions"))
+            .highlight_style(draw_highlight_style());
+        f.render_stateful_widget(list, chunks[1], &mut self.state);
+
+        f.set_cursor(chunks[0].x + self.input.len() as u16 + 1, chunks[0].y + 1);
+
+        Ok(())
+    }
+}
+
+impl AutocompleteWidget {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        match key {
+            Key::Char(c) => {
+                self.input.push(c);
+                self.input_changed = true;
+                self.filter();
+            }
+            Key::Backspace => {
+                self.input.pop();
+                self.input_changed = true;
+                self.filter();
+            }
+            Key::Down | Key::Tab => self.next(1),
+            Key::Up | Key::BackTab => self.previous(1),
+            Key::Ctrl('d') => self.next(10),
+            Key::Ctrl('u') => self.previous(10),
+            Key::Enter => {
+                let value = match self.selected() {
+                    Some(candidate) => candidate.clone(),
+                    None => self.input.trim().to_string(),
+                };
+                if !value.is_empty() {
+                    self.submitted = Some(value);
+                }
+            }
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+}
@@ -0,0 +1,191 @@
+use serde_json::{Map, Value};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{config::KeyConfig, event::key::Key};
+
+use super::{draw_block_style, draw_highlight_style, EventState};
+
+/// One change applied to every marked ticket.
+#[derive(Debug, Clone)]
+pub enum BulkOperation {
+    Assign {
+        account_id: Option<String>,
+        name: String,
+    },
+    Comment(String),
+    Label(String),
+    /// Transitions are matched by name, as their ids differ between workflows.
+    Transition {
+        name: String,
+        values: Map<String, Value>,
+    },
+}
+
+impl BulkOperation {
+    fn describe(&self) -> String {
+        match self {
+            Self::Assign { name, .. } => format!("Assign to {}", name),
+            Self::Comment(_) => "Add comment".to_string(),
+            Self::Label(label) => format!("Add label {}", label),
+            Self::Transition { name, .. } => format!("Transition to {}", name),
+        }
+    }
+}
+
+struct BulkResult {
+    key: String,
+    /// `None` until the ticket has been processed.
+    outcome: Option<Result<(), String>>,
+}
+
+pub struct BulkWidget {
+    key_config: KeyConfig,
+    results: Vec<BulkResult>,
+    state: TableState,
+    pub operation: Option<BulkOperation>,
+}
+
+impl BulkWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([Constraint::Length(1), Constraint::Min(1)].as_ref())
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let done = self.results.iter().filter(|r| r.outcome.is_some()).count();
+        let failed = self
+            .results
+            .iter()
+            .filter(|r| matches!(r.outcome, Some(Err(_))))
+            .count();
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let msg = if self.is_running() {
+            vec![Span::raw(format!(
+                "Working... {}/{} tickets",
+                done,
+                self.results.len()
+            ))]
+        } else {
+            vec![
+                Span::raw(format!(
+                    "{} succeeded, {} failed. Press ",
+                    done - failed,
+                    failed
+                )),
+                Span::styled("ESC", bold),
+                Span::raw(" to go back to the tickets."),
+            ]
+        };
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(Style::default().add_modifier(Modifier::UNDERLINED));
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        let title = match &self.operation {
+            Some(operation) => operation.describe(),
+            None => "Bulk".to_string(),
+        };
+        let rows = self.results.iter().map(|result| {
+            let (outcome, style) = match &result.outcome {
+                None => ("pending".to_string(), Style::default()),
+                Some(Ok(())) => ("done".to_string(), Style::default().fg(Color::Green)),
+                Some(Err(e)) => (e.clone(), Style::default().fg(Color::Red)),
+            };
+            Row::new([Cell::from(result.key.clone()), Cell::from(outcome)]).style(style)
+        });
+        let table = Table::new(rows)
+            .header(Row::new(["Key", "Result"]))
+            .block(draw_block_style(true, &title))
+            .highlight_style(draw_highlight_style())
+            .widths(&[Constraint::Percentage(15), Constraint::Percentage(85)]);
+        f.render_stateful_widget(table, chunks[1], &mut self.state);
+
+        Ok(())
+    }
+}
+
+impl BulkWidget {
+    pub fn new(key_config: KeyConfig) -> Self {
+        Self {
+            key_config,
+            operation: None,
+            results: Vec::new(),
+            state: TableState::default(),
+        }
+    }
+
+    pub fn start(&mut self, operation: BulkOperation, keys: Vec<String>) {
+        self.operation = Some(operation);
+        self.results = keys
+            .into_iter()
+            .map(|key| BulkResult { key, outcome: None })
+            .collect();
+        let selected = if self.results.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.state.select(selected);
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.next_pending().is_some()
+    }
+
+    /// The next ticket the operation still has to be applied to.
+    pub fn next_pending(&self) -> Option<&str> {
+        self.operation.as_ref()?;
+        self.results
+            .iter()
+            .find(|r| r.outcome.is_none())
+            .map(|r| r.key.as_str())
+    }
+
+    pub fn record(&mut self, key: &str, outcome: anyhow::Result<()>) {
+        if let Some(index) = self.results.iter().position(|r| r.key == key) {
+            self.results[index].outcome = Some(outcome.map_err(|e| e.to_string()));
+            self.state.select(Some(index));
+        }
+    }
+
+    pub fn next(&mut self, line: usize) {
+        if self.results.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.results.len() - 1));
+        self.state.select(i);
+    }
+
+    pub fn previous(&mut self, line: usize) {
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
+        self.state.select(i);
+    }
+}
+
+impl BulkWidget {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if key == self.key_config.scroll_down {
+            self.next(1);
+        } else if key == self.key_config.scroll_up {
+            self.previous(1);
+        } else if key == self.key_config.scroll_down_multiple_lines {
+            self.next(10);
+        } else if key == self.key_config.scroll_up_multiple_lines {
+            self.previous(10);
+        } else {
+            return Ok(EventState::NotConsumed);
+        }
+        Ok(EventState::Consumed)
+    }
+}
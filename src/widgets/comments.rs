@@ -8,8 +8,7 @@ use html2md::parse_html;
 use tui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::Style,
-    text::Span,
+    text::Text,
     widgets::{Cell, Clear, Paragraph, Row, Table, TableState, Wrap},
     Frame,
 };
@@ -17,6 +16,7 @@ use tui::{
 use crate::config::KeyConfig;
 
 use super::{
+    adf::render_adf,
     commands::{CommandInfo, CommandText},
     draw_block_style, draw_highlight_style, Component, EventState,
 };
@@ -57,7 +57,7 @@ impl Action {
 
 #[derive(Debug)]
 pub struct CommentsList {
-    comments_parsed: Option<String>,
+    comments_parsed: Option<Text<'static>>,
     scroll: u16,
     state: TableState,
     pub comments: Option<Comments>,
@@ -121,15 +121,18 @@ impl CommentsList {
         let text = match &self.comments_parsed {
             Some(c) => c.clone(),
             None => {
-                self.comments_parsed = Some(parse_html(&comment.rendered_body));
-                parse_html(&comment.rendered_body)
+                let text = match &comment.body {
+                    Some(adf) if adf.is_object() => render_adf(adf),
+                    _ => Text::from(parse_html(&comment.rendered_body)),
+                };
+                self.comments_parsed = Some(text.clone());
+                text
             }
         };
-        // let text = parse_html(&comment.rendered_body.clone());
-        let paragraph = Paragraph::new(Span::styled(text, Style::default()))
+        let paragraph = Paragraph::new(text)
             .alignment(Alignment::Left)
             .block(draw_block_style(focused, title))
-            .wrap(Wrap { trim: true });
+            .wrap(Wrap { trim: false });
 
         f.render_widget(paragraph, chunks[1]);
 
@@ -191,7 +194,7 @@ impl CommentsList {
         let i = self
             .state
             .selected()
-            .map(|i| if i <= line { 0 } else { i - line });
+            .map(|i| i.saturating_sub(line));
 
         self.state.select(i);
     }
@@ -243,6 +246,10 @@ impl CommentsList {
     }
 
     pub async fn update(&mut self, comments: Comments) -> anyhow::Result<()> {
+        self.comments_parsed = None;
+        let last = comments.comments.len().saturating_sub(1);
+        let selected = self.state.selected().unwrap_or(0).min(last);
+        self.state.select(Some(selected));
         self.comments = Some(comments);
         Ok(())
     }
@@ -1,14 +1,28 @@
-use crate::event::key::Key;
+use std::time::{Duration, Instant};
+
+use crate::{
+    event::key::Key,
+    jira::{adf::escape_markdown, users::User},
+};
+use simsearch::SimSearch;
 use tui::{
     backend::Backend,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans, Text},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
-use super::{EventState, InputMode};
+use super::{draw_highlight_style, EventState, InputMode};
+
+/// Jira is searched once the mention query stops changing for this long.
+const MENTION_SEARCH_DELAY: Duration = Duration::from_millis(300);
+
+/// The markdown of a comment typed as `lines`, kept as they are so code blocks stay intact.
+pub fn comment_markdown(lines: &[String]) -> String {
+    lines.join("\n")
+}
 
 // CommentPopup holds the state of the application
 #[derive(Debug)]
@@ -20,6 +34,17 @@ pub struct CommentAdd {
     /// History of recorded messages
     pub messages: Vec<String>,
     pub push_comment: bool,
+    /// Id of the comment being edited, the messages replace its body.
+    pub editing: Option<String>,
+    /// Byte offset of the `@` starting the mention being typed.
+    mention: Option<usize>,
+    mention_state: ListState,
+    mention_users: Vec<User>,
+    filtered_mentions: Vec<usize>,
+    /// Query to search Jira users with, set when typing `@` or when nothing matches.
+    pub search_mentions: Option<String>,
+    /// When the mention query last changed, a search is due once it settles.
+    mention_changed: Option<Instant>,
 }
 
 impl CommentAdd {
@@ -35,7 +60,10 @@ impl CommentAdd {
             .margin(2)
             .constraints(chunk_constraints)
             .split(f.size());
-        let input_title = "Add comments";
+        let input_title = match self.editing {
+            Some(_) => "Edit comment",
+            None => "Add comments",
+        };
 
         let normal_mode_style = (
             vec![
@@ -55,7 +83,9 @@ impl CommentAdd {
                 Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
                 Span::raw(" to stop editing, "),
                 Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" to record the message"),
+                Span::raw(" to record the message, "),
+                Span::styled("@", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to mention someone"),
             ],
             Style::default(),
         );
@@ -104,6 +134,35 @@ impl CommentAdd {
         let messages =
             List::new(messages).block(Block::default().borders(Borders::ALL).title("Messages"));
         f.render_widget(messages, chunks[2]);
+
+        if self.mention.is_some() {
+            self.draw_mentions(f, chunks[2])?;
+        }
+        Ok(())
+    }
+
+    fn draw_mentions<B: Backend>(&mut self, f: &mut Frame<B>, below: Rect) -> anyhow::Result<()> {
+        let height = (self.filtered_mentions.len() as u16 + 2).clamp(3, 10);
+        let area = Rect::new(
+            below.x + 1,
+            below.y,
+            below.width.saturating_sub(2).min(60),
+            height.min(below.height),
+        );
+        f.render_widget(Clear, area);
+        let items: Vec<ListItem> = self
+            .filtered_mentions
+            .iter()
+            .filter_map(|index| self.mention_users.get(*index))
+            .map(|user| match &user.email_address {
+                Some(email) => ListItem::new(format!("{} <{}>", user.display_name, email)),
+                None => ListItem::new(user.display_name.as_str()),
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title("Mention"))
+            .highlight_style(draw_highlight_style());
+        f.render_stateful_widget(list, area, &mut self.mention_state);
         Ok(())
     }
 }
@@ -115,9 +174,33 @@ impl CommentAdd {
             input_mode: InputMode::Normal,
             messages: Vec::new(),
             push_comment: false,
+            editing: None,
+            filtered_mentions: Vec::new(),
+            mention: None,
+            mention_state: ListState::default(),
+            mention_users: Vec::new(),
+            search_mentions: None,
+            mention_changed: None,
         }
     }
 
+    /// Fills the messages with the lines of `body`, pushing replaces the comment.
+    pub fn edit(&mut self, comment_id: &str, body: &str) {
+        self.clear();
+        self.editing = Some(comment_id.to_string());
+        self.messages = body.lines().map(String::from).collect();
+    }
+
+    pub fn clear(&mut self) {
+        self.editing = None;
+        self.mention = None;
+        self.mention_changed = None;
+        self.input.clear();
+        self.input_mode = InputMode::Normal;
+        self.messages.clear();
+        self.push_comment = false;
+    }
+
     pub fn edit_mode(&mut self) {
         self.input_mode = InputMode::Editing
     }
@@ -140,8 +223,140 @@ impl CommentAdd {
         }
     }
 
+    /// Text typed after the `@` of the current mention.
+    fn mention_query(&self) -> Option<&str> {
+        self.mention.map(|start| &self.input[start + 1..])
+    }
+
+    /// The mention query, once it hasn't changed for `MENTION_SEARCH_DELAY`.
+    pub fn settled_mention_query(&mut self) -> Option<String> {
+        let changed = self.mention_changed?;
+        if changed.elapsed() < MENTION_SEARCH_DELAY {
+            return None;
+        }
+        self.mention_changed = None;
+        self.mention_query().map(String::from)
+    }
+
+    pub fn update_mentions(&mut self, users: Vec<User>) {
+        self.mention_users = users
+            .into_iter()
+            .filter(|user| user.account_id.is_some())
+            .collect();
+        self.filter_mentions();
+    }
+
+    fn filter_mentions(&mut self) {
+        let query = self.mention_query().unwrap_or_default().to_string();
+        if query.is_empty() {
+            self.filtered_mentions = (0..self.mention_users.len()).collect();
+        } else {
+            let mut engine: SimSearch<usize> = SimSearch::new();
+            for (index, user) in self.mention_users.iter().enumerate() {
+                let email = user.email_address.as_deref().unwrap_or_default();
+                engine.insert_tokens(index, &[user.display_name.as_str(), email]);
+            }
+            self.filtered_mentions = engine.search(&query);
+        }
+        let selected = if self.filtered_mentions.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.mention_state.select(selected);
+    }
+
+    fn selected_mention(&self) -> Option<&User> {
+        self.mention_state
+            .selected()
+            .and_then(|i| self.filtered_mentions.get(i))
+            .and_then(|index| self.mention_users.get(*index))
+    }
+
+    fn move_mention(&mut self, line: isize) {
+        if self.filtered_mentions.is_empty() {
+            return;
+        }
+        let last = self.filtered_mentions.len() - 1;
+        let i = self
+            .mention_state
+            .selected()
+            .map(|i| i.saturating_add_signed(line).min(last));
+        self.mention_state.select(i);
+    }
+
+    /// Replaces the typed `@query` with a link to the user's account id, which
+    /// `markdown_to_adf` turns into a mention node.
+    fn choose_mention(&mut self) {
+        let start = match self.mention {
+            None => return,
+            Some(start) => start,
+        };
+        // Users without an account id can't be mentioned, `update_mentions` leaves them out
+        let user = self
+            .selected_mention()
+            .and_then(|user| Some((user.account_id.as_deref()?, user)));
+        match user {
+            Some((account_id, user)) => {
+                let mention = format!(
+                    "[@{}](accountid:{}) ",
+                    escape_markdown(&user.display_name),
+                    account_id
+                );
+                self.input.truncate(start);
+                self.input.push_str(&mention);
+                self.mention = None;
+            }
+            None => {
+                let query = self.mention_query().unwrap_or_default();
+                if !query.is_empty() {
+                    self.search_mentions = Some(query.to_string());
+                }
+            }
+        }
+    }
+
+    fn mention_key_event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        match key {
+            Key::Char(c) => {
+                self.input.push(c);
+                self.filter_mentions();
+                self.mention_changed = Some(Instant::now());
+            }
+            Key::Backspace => {
+                self.input.pop();
+                if self.mention.is_some_and(|start| self.input.len() <= start) {
+                    self.mention = None;
+                    self.mention_changed = None;
+                } else {
+                    self.filter_mentions();
+                    self.mention_changed = Some(Instant::now());
+                }
+            }
+            Key::Down | Key::Tab => self.move_mention(1),
+            Key::Up | Key::BackTab => self.move_mention(-1),
+            Key::Enter => self.choose_mention(),
+            Key::Esc => {
+                self.mention = None;
+                self.mention_changed = None;
+            }
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+
     fn edit_mode_key_event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if self.mention.is_some() {
+            return self.mention_key_event(key);
+        }
         match key {
+            Key::Char('@') if self.input.is_empty() || self.input.ends_with(' ') => {
+                self.mention = Some(self.input.len());
+                self.input.push('@');
+                self.search_mentions = Some(String::new());
+                self.update_mentions(Vec::new());
+                Ok(EventState::Consumed)
+            }
             Key::Char(c) => {
                 self.input.push(c);
                 Ok(EventState::Consumed)
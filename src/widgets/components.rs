@@ -14,7 +14,13 @@ use super::{draw_block_style, draw_highlight_style, EventState};
 pub struct ComponentsWidget {
     key_config: KeyConfig,
     components: Vec<String>,
+    /// Components of the ticket's project, as fetched from Jira.
+    available_components: Vec<String>,
+    /// The toggles shown while focused: the project's components, then any other of the ticket's.
+    project_components: Vec<String>,
     state: ListState,
+    pub project_key: Option<String>,
+    pub toggled: Option<String>,
 }
 
 impl ComponentsWidget {
@@ -40,12 +46,26 @@ impl ComponentsWidget {
             Some(ticket_data) => ticket_data,
         };
 
-        let list_items: Vec<_> = ticket
-            .fields
-            .components
-            .iter()
-            .map(|component| ListItem::new(component.name.as_str()))
-            .collect();
+        let list_items: Vec<_> = if focused {
+            self.project_components
+                .iter()
+                .map(|name| {
+                    let mark = if self.has_component(name) {
+                        "[x]"
+                    } else {
+                        "[ ]"
+                    };
+                    ListItem::new(format!("{} {}", mark, name))
+                })
+                .collect()
+        } else {
+            ticket
+                .fields
+                .components
+                .iter()
+                .map(|component| ListItem::new(component.name.as_str()))
+                .collect()
+        };
 
         let list = List::new(list_items)
             .block(draw_block_style(focused, title))
@@ -64,18 +84,22 @@ impl ComponentsWidget {
         Self {
             key_config,
             components: vec![],
+            available_components: vec![],
+            project_components: vec![],
             state,
+            project_key: None,
+            toggled: None,
         }
     }
 
     pub fn next(&mut self, line: usize) {
-        if self.components.is_empty() {
+        if self.project_components.is_empty() {
             return;
         }
         let i = self
             .state
             .selected()
-            .map(|i| (i + line).min(self.components.len() - 1));
+            .map(|i| (i + line).min(self.project_components.len() - 1));
 
         self.state.select(i);
     }
@@ -84,28 +108,28 @@ impl ComponentsWidget {
         let i = self
             .state
             .selected()
-            .map(|i| if i <= line { 0 } else { i - line });
+            .map(|i| i.saturating_sub(line));
 
         self.state.select(i);
     }
 
     pub fn go_to_top(&mut self) {
-        if self.components.is_empty() {
+        if self.project_components.is_empty() {
             return;
         }
         self.state.select(Some(0));
     }
 
     pub fn go_to_bottom(&mut self) {
-        if self.components.is_empty() {
+        if self.project_components.is_empty() {
             return;
         }
-        self.state.select(Some(self.components.len() - 1));
+        self.state.select(Some(self.project_components.len() - 1));
     }
 
     pub fn selected(&self) -> Option<&String> {
         match self.state.selected() {
-            Some(i) => self.components.get(i),
+            Some(i) => self.project_components.get(i),
             None => None,
         }
     }
@@ -115,8 +139,27 @@ impl ComponentsWidget {
             .iter()
             .map(|component| component.name.clone())
             .collect();
+        self.project_components = self.available_components.clone();
+        for name in &self.components {
+            if !self.project_components.contains(name) {
+                self.project_components.push(name.clone());
+            }
+        }
         Ok(())
     }
+
+    /// Every component of the ticket's project, shown as toggles while the pane is focused.
+    pub fn update_project_components(&mut self, project_key: &str, components: &[Components]) {
+        self.project_key = Some(project_key.to_string());
+        self.available_components = components
+            .iter()
+            .map(|component| component.name.clone())
+            .collect();
+    }
+
+    pub fn has_component(&self, name: &str) -> bool {
+        self.components.iter().any(|c| c == name)
+    }
 }
 
 impl ComponentsWidget {
@@ -139,6 +182,9 @@ impl ComponentsWidget {
         } else if key == self.key_config.scroll_to_top {
             self.go_to_top();
             return Ok(EventState::Consumed);
+        } else if key == self.key_config.enter {
+            self.toggled = self.selected().cloned();
+            return Ok(EventState::Consumed);
         }
         Ok(EventState::NotConsumed)
     }
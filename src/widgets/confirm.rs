@@ -0,0 +1,78 @@
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::event::key::Key;
+
+use super::{draw_block_style, EventState};
+
+/// Yes/no popup guarding destructive actions.
+pub struct ConfirmWidget {
+    message: String,
+    pub confirmed: Option<bool>,
+}
+
+impl ConfirmWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let width = 60;
+        let height = 6;
+        let area = Rect::new(
+            (f.size().width.saturating_sub(width)) / 2,
+            (f.size().height.saturating_sub(height)) / 2,
+            width.min(f.size().width),
+            height.min(f.size().height),
+        );
+        f.render_widget(Clear, area);
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let text = Text::from(vec![
+            Spans::from(self.message.as_str()),
+            Spans::from(""),
+            Spans::from(vec![
+                Span::raw("Press "),
+                Span::styled("y", bold),
+                Span::raw(" to confirm, "),
+                Span::styled("n", bold),
+                Span::raw(" or "),
+                Span::styled("Esc", bold),
+                Span::raw(" to cancel."),
+            ]),
+        ]);
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .block(draw_block_style(true, "Confirm"));
+        f.render_widget(paragraph, area);
+
+        Ok(())
+    }
+}
+
+impl ConfirmWidget {
+    pub fn new() -> Self {
+        Self {
+            confirmed: None,
+            message: String::new(),
+        }
+    }
+
+    pub fn open(&mut self, message: &str) {
+        self.confirmed = None;
+        self.message = message.to_string();
+    }
+}
+
+impl ConfirmWidget {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        match key {
+            Key::Char('y') | Key::Char('Y') => self.confirmed = Some(true),
+            Key::Char('n') | Key::Char('N') | Key::Esc => self.confirmed = Some(false),
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+}
@@ -0,0 +1,250 @@
+use serde_json::{Map, Value};
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    config::KeyConfig,
+    event::key::Key,
+    jira::{
+        auth::JiraClient,
+        fields::{FieldAllowedValue, FieldMeta},
+    },
+};
+
+use super::{draw_block_style, draw_highlight_style, EventState, InputMode};
+
+/// Form with one line per Jira field, shared by ticket creation and transitions.
+/// Fields with allowed values cycle through them, the others take typed text.
+pub struct FieldForm {
+    inputs: Vec<String>,
+    selections: Vec<Option<usize>>,
+    state: ListState,
+    pub fields: Vec<FieldMeta>,
+    pub input_mode: InputMode,
+}
+
+impl FieldForm {
+    pub fn draw<B: Backend>(
+        &mut self,
+        f: &mut Frame<B>,
+        list_area: Rect,
+        input_area: Rect,
+        title: &str,
+    ) -> anyhow::Result<()> {
+        let list_items: Vec<ListItem> = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let required = if field.required { "*" } else { "" };
+                let value = match &field.allowed_values {
+                    Some(allowed) if !allowed.is_empty() => {
+                        let selected = self.selections[index]
+                            .and_then(|i| allowed.get(i))
+                            .map(|v| v.label())
+                            .unwrap_or("none");
+                        format!("< {} >", selected)
+                    }
+                    _ => self.inputs[index].clone(),
+                };
+                ListItem::new(format!("{}{}: {}", field.name, required, value))
+            })
+            .collect();
+        let list = List::new(list_items)
+            .block(draw_block_style(
+                self.input_mode == InputMode::Normal,
+                title,
+            ))
+            .highlight_style(draw_highlight_style());
+        f.render_stateful_widget(list, list_area, &mut self.state);
+
+        let (field_name, field_input, hint) = match self.state.selected() {
+            Some(i) => (
+                self.fields[i].name.as_str(),
+                self.inputs[i].as_str(),
+                self.fields[i].input_hint(),
+            ),
+            None => ("", "", None),
+        };
+        let input_title = match hint {
+            Some(hint) => format!("{} ({})", field_name, hint),
+            None => field_name.to_string(),
+        };
+        let input = Paragraph::new(field_input)
+            .wrap(Wrap { trim: true })
+            .style(match self.input_mode {
+                InputMode::Normal => Style::default(),
+                InputMode::Editing => Style::default().fg(Color::Yellow),
+            })
+            .block(Block::default().borders(Borders::ALL).title(input_title));
+        f.render_widget(input, input_area);
+
+        if self.input_mode == InputMode::Editing {
+            f.set_cursor(
+                input_area.x + field_input.len() as u16 + 1,
+                input_area.y + 1,
+            )
+        }
+
+        Ok(())
+    }
+}
+
+impl FieldForm {
+    pub fn new() -> Self {
+        Self {
+            fields: Vec::new(),
+            input_mode: InputMode::Normal,
+            inputs: Vec::new(),
+            selections: Vec::new(),
+            state: ListState::default(),
+        }
+    }
+
+    pub fn update(&mut self, fields: Vec<FieldMeta>) {
+        self.fields = fields;
+        self.inputs = vec![String::new(); self.fields.len()];
+        self.selections = vec![None; self.fields.len()];
+        self.input_mode = InputMode::Normal;
+        let selected = if self.fields.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.state.select(selected);
+    }
+
+    pub fn clear(&mut self) {
+        self.update(Vec::new());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    fn selected_value(&self, index: usize) -> Option<&FieldAllowedValue> {
+        let allowed = self.fields[index].allowed_values.as_ref()?;
+        self.selections[index].and_then(|i| allowed.get(i))
+    }
+
+    /// The values Jira expects keyed by field id, fields left empty are skipped.
+    pub fn values(&self, jira_client: &JiraClient) -> anyhow::Result<Map<String, Value>> {
+        let mut values = Map::new();
+        for (index, field) in self.fields.iter().enumerate() {
+            let selected = self.selected_value(index);
+            if let Some(value) = field.to_value(&self.inputs[index], selected, jira_client)? {
+                values.insert(field.id().to_string(), value);
+            }
+        }
+        Ok(values)
+    }
+
+    /// Names of the required fields that have no value yet.
+    pub fn missing_required(&self, jira_client: &JiraClient) -> anyhow::Result<Vec<&str>> {
+        let values = self.values(jira_client)?;
+        Ok(self
+            .fields
+            .iter()
+            .filter(|field| field.required && !field.has_default_value.unwrap_or(false))
+            .filter(|field| !values.contains_key(field.id()))
+            .map(|field| field.name.as_str())
+            .collect())
+    }
+
+    /// User fields with the text typed in them, to be looked up as Jira users.
+    pub fn user_inputs(&self) -> Vec<(&FieldMeta, &str)> {
+        self.fields
+            .iter()
+            .zip(&self.inputs)
+            .filter(|(field, input)| field.is_user() && !input.trim().is_empty())
+            .map(|(field, input)| (field, input.trim()))
+            .collect()
+    }
+
+    fn next(&mut self, line: usize) {
+        if self.fields.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.fields.len() - 1));
+        self.state.select(i);
+    }
+
+    fn previous(&mut self, line: usize) {
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
+        self.state.select(i);
+    }
+
+    fn cycle_value(&mut self, forward: bool) {
+        let index = match self.state.selected() {
+            Some(i) => i,
+            None => return,
+        };
+        let len = match &self.fields[index].allowed_values {
+            Some(allowed) if !allowed.is_empty() => allowed.len(),
+            _ => return,
+        };
+        self.selections[index] = match (self.selections[index], forward) {
+            (None, true) => Some(0),
+            (None, false) => Some(len - 1),
+            (Some(i), true) if i + 1 >= len => None,
+            (Some(i), true) => Some(i + 1),
+            (Some(0), false) => None,
+            (Some(i), false) => Some(i - 1),
+        };
+    }
+}
+
+impl FieldForm {
+    fn normal_mode_key_event(&mut self, key: Key, key_config: &KeyConfig) -> EventState {
+        if key == key_config.scroll_down || key == key_config.move_down {
+            self.next(1);
+        } else if key == key_config.scroll_up || key == key_config.move_up {
+            self.previous(1);
+        } else if key == key_config.scroll_right || key == key_config.move_right {
+            self.cycle_value(true);
+        } else if key == key_config.scroll_left || key == key_config.move_left {
+            self.cycle_value(false);
+        } else if key == Key::Char('e') || key == key_config.enter {
+            match self.state.selected() {
+                Some(i) if !self.fields[i].has_allowed_values() => {
+                    self.input_mode = InputMode::Editing
+                }
+                _ => return EventState::NotConsumed,
+            }
+        } else {
+            return EventState::NotConsumed;
+        }
+        EventState::Consumed
+    }
+
+    fn edit_mode_key_event(&mut self, key: Key) -> EventState {
+        let index = match self.state.selected() {
+            Some(i) => i,
+            None => return EventState::NotConsumed,
+        };
+        match key {
+            Key::Char(c) => self.inputs[index].push(c),
+            Key::Backspace => {
+                self.inputs[index].pop();
+            }
+            Key::Esc | Key::Enter => self.input_mode = InputMode::Normal,
+            _ => return EventState::NotConsumed,
+        }
+        EventState::Consumed
+    }
+
+    pub fn event(&mut self, key: Key, key_config: &KeyConfig) -> EventState {
+        match self.input_mode {
+            InputMode::Normal => self.normal_mode_key_event(key, key_config),
+            InputMode::Editing => self.edit_mode_key_event(key),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{config::KeyConfig, event::key::Key, jira::filters::Filter};
+
+use super::{draw_block_style, draw_highlight_style, EventState};
+
+pub struct FiltersWidget {
+    filters: Vec<Filter>,
+    key_config: KeyConfig,
+    state: TableState,
+    pub chosen_filter: Option<Filter>,
+    pub reload_filters: bool,
+}
+
+impl FiltersWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([Constraint::Length(1), Constraint::Min(1)].as_ref())
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let msg = vec![
+            Span::raw("Press "),
+            Span::styled("ESC", bold),
+            Span::raw(" to exit, "),
+            Span::styled("Enter", bold),
+            Span::raw(" to list the filter's tickets, "),
+            Span::styled(self.key_config.reset.to_string(), bold),
+            Span::raw(" to reload the favourite filters from Jira."),
+        ];
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(Style::default().add_modifier(Modifier::UNDERLINED));
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        let headers = Row::new(["Filter", "Owner", "JQL"]);
+        let rows = self.filters.iter().map(|filter| {
+            let owner = match &filter.owner {
+                Some(o) => o.display_name.as_str(),
+                None => "",
+            };
+            Row::new([
+                Cell::from(filter.name.as_str()),
+                Cell::from(owner),
+                Cell::from(filter.jql.as_str()),
+            ])
+        });
+        let table = Table::new(rows)
+            .header(headers)
+            .block(draw_block_style(true, "Favourite filters"))
+            .highlight_style(draw_highlight_style())
+            .widths(&[
+                Constraint::Percentage(25),
+                Constraint::Percentage(15),
+                Constraint::Percentage(60),
+            ]);
+        f.render_stateful_widget(table, chunks[1], &mut self.state);
+
+        Ok(())
+    }
+}
+
+impl FiltersWidget {
+    pub fn new(key_config: KeyConfig) -> Self {
+        Self {
+            chosen_filter: None,
+            filters: Vec::new(),
+            key_config,
+            reload_filters: false,
+            state: TableState::default(),
+        }
+    }
+
+    pub fn update(&mut self, filters: Vec<Filter>) {
+        self.filters = filters;
+        let selected = match self.state.selected() {
+            _ if self.filters.is_empty() => None,
+            Some(i) => Some(i.min(self.filters.len() - 1)),
+            None => Some(0),
+        };
+        self.state.select(selected);
+    }
+
+    pub fn selected(&self) -> Option<&Filter> {
+        match self.state.selected() {
+            Some(i) => self.filters.get(i),
+            None => None,
+        }
+    }
+
+    pub fn next(&mut self, line: usize) {
+        if self.filters.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.filters.len() - 1));
+        self.state.select(i);
+    }
+
+    pub fn previous(&mut self, line: usize) {
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
+        self.state.select(i);
+    }
+}
+
+impl FiltersWidget {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if key == self.key_config.scroll_down || key == self.key_config.move_down {
+            self.next(1);
+        } else if key == self.key_config.scroll_up || key == self.key_config.move_up {
+            self.previous(1);
+        } else if key == self.key_config.scroll_down_multiple_lines {
+            self.next(10);
+        } else if key == self.key_config.scroll_up_multiple_lines {
+            self.previous(10);
+        } else if key == self.key_config.reset {
+            self.reload_filters = true;
+        } else if key == self.key_config.enter {
+            self.chosen_filter = self.selected().cloned();
+        } else {
+            return Ok(EventState::NotConsumed);
+        }
+        Ok(EventState::Consumed)
+    }
+}
@@ -120,12 +120,11 @@ impl HelpWidget {
         } else {
             new_selection.saturating_sub(1)
         };
-        new_selection = new_selection.max(0);
 
         self.selection = new_selection.min(self.cmds.len().saturating_sub(1) as u16);
     }
 
-    fn get_text(&self, width: usize) -> Vec<Spans> {
+    fn get_text(&self, width: usize) -> Vec<Spans<'_>> {
         let mut txt: Vec<Spans> = Vec::new();
 
         let mut processed = 0;
@@ -141,7 +140,7 @@ impl HelpWidget {
                 processed += 1;
 
                 txt.push(Spans::from(Span::styled(
-                    format!(" {}{:w$}", command_info.text.name, w = width),
+                    format!(" {}{width:width$}", command_info.text.name),
                     if is_selected {
                         Style::default().bg(Color::Blue)
                     } else {
@@ -0,0 +1,176 @@
+use chrono::DateTime;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    config::KeyConfig,
+    event::key::Key,
+    jira::tickets::{ChangeItem, History},
+};
+
+use super::{draw_block_style, draw_highlight_style, EventState};
+
+/// One changed field, with the when and who of its history entry.
+struct Change {
+    author: String,
+    created: String,
+    item: ChangeItem,
+}
+
+pub struct HistoryWidget {
+    changes: Vec<Change>,
+    key_config: KeyConfig,
+    state: TableState,
+    title: String,
+    pub reload_history: bool,
+}
+
+impl HistoryWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([Constraint::Length(1), Constraint::Min(1)].as_ref())
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let msg = vec![
+            Span::raw("Press "),
+            Span::styled("ESC", bold),
+            Span::raw(" to exit, "),
+            Span::styled(self.key_config.reset.to_string(), bold),
+            Span::raw(" to reload the history from Jira."),
+        ];
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(Style::default().add_modifier(Modifier::UNDERLINED));
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        let headers = Row::new(["When", "Who", "Field", "From", "To"]);
+        let rows = self.changes.iter().map(|change| {
+            let from = change.item.from.as_deref().unwrap_or_default();
+            let to = change.item.to.as_deref().unwrap_or_default();
+            let height = from.lines().count().max(to.lines().count()).max(1);
+            Row::new([
+                Cell::from(change.created.as_str()),
+                Cell::from(change.author.as_str()),
+                Cell::from(change.item.field.as_str()),
+                Cell::from(from),
+                Cell::from(to),
+            ])
+            .height(height as u16)
+        });
+        let table = Table::new(rows)
+            .header(headers)
+            .block(draw_block_style(true, &self.title))
+            .highlight_style(draw_highlight_style())
+            .widths(&[
+                Constraint::Percentage(15),
+                Constraint::Percentage(15),
+                Constraint::Percentage(14),
+                Constraint::Percentage(28),
+                Constraint::Percentage(28),
+            ]);
+        f.render_stateful_widget(table, chunks[1], &mut self.state);
+
+        Ok(())
+    }
+}
+
+impl HistoryWidget {
+    pub fn new(key_config: KeyConfig) -> Self {
+        Self {
+            changes: Vec::new(),
+            key_config,
+            reload_history: false,
+            state: TableState::default(),
+            title: String::new(),
+        }
+    }
+
+    pub fn update(&mut self, ticket_key: &str, histories: Vec<History>) {
+        self.title = format!("{} history", ticket_key);
+        self.changes = histories
+            .into_iter()
+            .flat_map(|history| {
+                let author = match history.author {
+                    Some(a) => a.display_name,
+                    None => "Automation".to_string(),
+                };
+                let created = DateTime::parse_from_str(&history.created, "%Y-%m-%dT%H:%M:%S%.3f%z")
+                    .map(|created| created.format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or(history.created);
+                history.items.into_iter().map(move |item| Change {
+                    author: author.clone(),
+                    created: created.clone(),
+                    item,
+                })
+            })
+            .collect();
+        let selected = if self.changes.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.state.select(selected);
+    }
+
+    pub fn next(&mut self, line: usize) {
+        if self.changes.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.changes.len() - 1));
+        self.state.select(i);
+    }
+
+    pub fn previous(&mut self, line: usize) {
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
+        self.state.select(i);
+    }
+
+    pub fn go_to_top(&mut self) {
+        if self.changes.is_empty() {
+            return;
+        }
+        self.state.select(Some(0));
+    }
+
+    pub fn go_to_bottom(&mut self) {
+        if self.changes.is_empty() {
+            return;
+        }
+        self.state.select(Some(self.changes.len() - 1));
+    }
+}
+
+impl HistoryWidget {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if key == self.key_config.scroll_down {
+            self.next(1);
+        } else if key == self.key_config.scroll_up {
+            self.previous(1);
+        } else if key == self.key_config.scroll_down_multiple_lines {
+            self.next(10);
+        } else if key == self.key_config.scroll_up_multiple_lines {
+            self.previous(10);
+        } else if key == self.key_config.scroll_to_bottom {
+            self.go_to_bottom();
+        } else if key == self.key_config.scroll_to_top {
+            self.go_to_top();
+        } else if key == self.key_config.reset {
+            self.reload_history = true;
+        } else {
+            return Ok(EventState::NotConsumed);
+        }
+        Ok(EventState::Consumed)
+    }
+}
@@ -0,0 +1,312 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{
+    config::KeyConfig,
+    event::key::Key,
+    jira::{
+        agile::BoardColumn,
+        tickets::{Status, TicketData},
+    },
+};
+
+use super::{draw_block_style, draw_highlight_style, EventState};
+
+/// Statuses mapped to a column, by id when Jira gives one and by name otherwise.
+#[derive(Clone, Default)]
+pub struct ColumnStatuses {
+    ids: Vec<String>,
+    names: Vec<String>,
+}
+
+impl ColumnStatuses {
+    /// Whether a ticket in `status` belongs to the column.
+    pub fn accepts(&self, status: &Status) -> bool {
+        match &status.id {
+            Some(id) if self.ids.contains(id) => true,
+            _ => self.names.contains(&status.name),
+        }
+    }
+}
+
+struct KanbanColumn {
+    name: String,
+    state: ListState,
+    statuses: ColumnStatuses,
+    tickets: Vec<TicketData>,
+}
+
+impl KanbanColumn {
+    fn new(name: &str, statuses: ColumnStatuses) -> Self {
+        Self {
+            name: name.to_string(),
+            state: ListState::default(),
+            statuses,
+            tickets: Vec::new(),
+        }
+    }
+}
+
+/// A card the user asked to move, and the column it should land in.
+pub struct CardMove {
+    pub column: String,
+    pub statuses: ColumnStatuses,
+    pub ticket: TicketData,
+}
+
+/// Tickets laid out in columns, from the board configuration or grouped by status.
+pub struct KanbanWidget {
+    board_columns: Option<Vec<BoardColumn>>,
+    columns: Vec<KanbanColumn>,
+    key_config: KeyConfig,
+    selected_column: usize,
+    title: String,
+    pub chosen_ticket: Option<String>,
+    pub move_card: Option<CardMove>,
+}
+
+impl KanbanWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([Constraint::Length(1), Constraint::Min(1)].as_ref())
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let msg = vec![
+            Span::styled(self.title.as_str(), bold),
+            Span::raw(" - Press "),
+            Span::styled("ESC", bold),
+            Span::raw(" to exit, "),
+            Span::styled("h/l", bold),
+            Span::raw(" to switch column, "),
+            Span::styled(self.key_config.card_move_left.to_string(), bold),
+            Span::raw("/"),
+            Span::styled(self.key_config.card_move_right.to_string(), bold),
+            Span::raw(" to move the card, "),
+            Span::styled("Enter", bold),
+            Span::raw(" to select the ticket."),
+        ];
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(Style::default().add_modifier(Modifier::UNDERLINED));
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        if self.columns.is_empty() {
+            return Ok(());
+        }
+        let constraints: Vec<Constraint> = self
+            .columns
+            .iter()
+            .map(|_| Constraint::Ratio(1, self.columns.len() as u32))
+            .collect();
+        let column_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(chunks[1]);
+
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            let focused = i == self.selected_column;
+            let items: Vec<ListItem> = column
+                .tickets
+                .iter()
+                .map(|ticket| {
+                    let assignee = match &ticket.fields.assignee {
+                        Some(a) => a.display_name.as_str(),
+                        None => "Unassigned",
+                    };
+                    ListItem::new(vec![
+                        Spans::from(vec![
+                            Span::styled(ticket.key.as_str(), bold),
+                            Span::raw(format!(" {}", assignee)),
+                        ]),
+                        Spans::from(ticket.fields.summary.as_str()),
+                        Spans::from(""),
+                    ])
+                })
+                .collect();
+            let title = format!("{} ({})", column.name, column.tickets.len());
+            let mut list = List::new(items).block(draw_block_style(focused, &title));
+            if focused {
+                list = list.highlight_style(draw_highlight_style());
+            }
+            f.render_stateful_widget(list, column_chunks[i], &mut column.state);
+        }
+
+        Ok(())
+    }
+}
+
+impl KanbanWidget {
+    pub fn new(key_config: KeyConfig) -> Self {
+        Self {
+            board_columns: None,
+            chosen_ticket: None,
+            columns: Vec::new(),
+            key_config,
+            move_card: None,
+            selected_column: 0,
+            title: String::new(),
+        }
+    }
+
+    /// Sets the columns from `board_columns`, or from the tickets' statuses when there is no board.
+    pub fn update(
+        &mut self,
+        title: &str,
+        board_columns: Option<Vec<BoardColumn>>,
+        tickets: &[TicketData],
+    ) {
+        self.title = title.to_string();
+        self.board_columns = board_columns;
+        self.columns.clear();
+        self.selected_column = 0;
+        self.refresh(tickets);
+    }
+
+    /// Lays `tickets` out again, keeping the selected ticket when it is still there.
+    pub fn refresh(&mut self, tickets: &[TicketData]) {
+        let selected_key = self.selected().map(|t| t.key.clone());
+        self.columns = match &self.board_columns {
+            Some(board_columns) => board_columns
+                .iter()
+                .map(|board_column| {
+                    let statuses = ColumnStatuses {
+                        ids: board_column.statuses.iter().map(|s| s.id.clone()).collect(),
+                        names: Vec::new(),
+                    };
+                    KanbanColumn::new(&board_column.name, statuses)
+                })
+                .collect(),
+            None => status_columns(tickets),
+        };
+        // Tickets in a status the board doesn't map are left out, as in Jira.
+        for ticket in tickets {
+            let status = &ticket.fields.status;
+            if let Some(column) = self.columns.iter_mut().find(|c| c.statuses.accepts(status)) {
+                column.tickets.push(ticket.clone());
+            }
+        }
+        for column in self.columns.iter_mut() {
+            if !column.tickets.is_empty() {
+                column.state.select(Some(0));
+            }
+        }
+        self.selected_column = self
+            .selected_column
+            .min(self.columns.len().saturating_sub(1));
+        if let Some(key) = selected_key {
+            self.select_ticket(&key);
+        }
+    }
+
+    pub fn select_ticket(&mut self, key: &str) {
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            if let Some(row) = column.tickets.iter().position(|t| t.key == key) {
+                column.state.select(Some(row));
+                self.selected_column = i;
+                return;
+            }
+        }
+    }
+
+    pub fn selected(&self) -> Option<&TicketData> {
+        let column = self.columns.get(self.selected_column)?;
+        column.tickets.get(column.state.selected()?)
+    }
+
+    fn move_selection(&mut self, forward: bool, line: usize) {
+        let column = match self.columns.get_mut(self.selected_column) {
+            Some(c) if !c.tickets.is_empty() => c,
+            _ => return,
+        };
+        let len = column.tickets.len();
+        let i = column.state.selected().map(|i| match forward {
+            true => (i + line).min(len - 1),
+            false => i.saturating_sub(line),
+        });
+        column.state.select(i);
+    }
+
+    fn request_move(&mut self, forward: bool) {
+        let target = match forward {
+            true => self.selected_column + 1,
+            false => match self.selected_column.checked_sub(1) {
+                Some(i) => i,
+                None => return,
+            },
+        };
+        let (ticket, column) = match (self.selected(), self.columns.get(target)) {
+            (Some(ticket), Some(column)) => (ticket.clone(), column),
+            _ => return,
+        };
+        self.move_card = Some(CardMove {
+            column: column.name.clone(),
+            statuses: column.statuses.clone(),
+            ticket,
+        });
+    }
+}
+
+/// One column per status, ordered by status category (to do, in progress, done).
+fn status_columns(tickets: &[TicketData]) -> Vec<KanbanColumn> {
+    let mut statuses: Vec<&Status> = Vec::new();
+    for ticket in tickets {
+        let status = &ticket.fields.status;
+        if !statuses.iter().any(|s| s.name == status.name) {
+            statuses.push(status);
+        }
+    }
+    let category_rank = |status: &Status| match status.status_category.as_ref() {
+        Some(c) if c.key == "new" => 0,
+        Some(c) if c.key == "done" => 2,
+        _ => 1,
+    };
+    statuses.sort_by_key(|s| category_rank(s));
+    statuses
+        .into_iter()
+        .map(|status| {
+            let statuses = ColumnStatuses {
+                ids: status.id.iter().cloned().collect(),
+                names: vec![status.name.clone()],
+            };
+            KanbanColumn::new(&status.name, statuses)
+        })
+        .collect()
+}
+
+impl KanbanWidget {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if key == self.key_config.scroll_down || key == self.key_config.move_down {
+            self.move_selection(true, 1);
+        } else if key == self.key_config.scroll_up || key == self.key_config.move_up {
+            self.move_selection(false, 1);
+        } else if key == self.key_config.scroll_down_multiple_lines {
+            self.move_selection(true, 10);
+        } else if key == self.key_config.scroll_up_multiple_lines {
+            self.move_selection(false, 10);
+        } else if key == self.key_config.scroll_left || key == self.key_config.move_left {
+            self.selected_column = self.selected_column.saturating_sub(1);
+        } else if key == self.key_config.scroll_right || key == self.key_config.move_right {
+            if self.selected_column + 1 < self.columns.len() {
+                self.selected_column += 1;
+            }
+        } else if key == self.key_config.card_move_left {
+            self.request_move(false);
+        } else if key == self.key_config.card_move_right {
+            self.request_move(true);
+        } else if key == self.key_config.enter {
+            self.chosen_ticket = self.selected().map(|t| t.key.clone());
+        } else {
+            return Ok(EventState::NotConsumed);
+        }
+        Ok(EventState::Consumed)
+    }
+}
@@ -84,7 +84,7 @@ impl LabelsWidget {
         let i = self
             .state
             .selected()
-            .map(|i| if i <= line { 0 } else { i - line });
+            .map(|i| i.saturating_sub(line));
 
         self.state.select(i);
     }
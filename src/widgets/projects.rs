@@ -46,7 +46,7 @@ pub struct ProjectsWidget {
 }
 
 impl ProjectsWidget {
-    pub fn new(projects: &Vec<Project>, key_config: KeyConfig) -> Self {
+    pub fn new(projects: &[Project], key_config: KeyConfig) -> Self {
         let mut state = ListState::default();
         if !projects.is_empty() {
             state.select(Some(0));
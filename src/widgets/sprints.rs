@@ -0,0 +1,198 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    config::KeyConfig,
+    event::key::Key,
+    jira::agile::{Board, Sprint},
+};
+
+use super::{draw_block_style, draw_highlight_style, EventState};
+
+/// Date part of a Jira timestamp.
+pub fn sprint_date(date: &Option<String>) -> &str {
+    match date {
+        Some(d) => d.get(..10).unwrap_or(d),
+        None => "",
+    }
+}
+
+/// Boards of the project on the left, sprints of the selected board on the right.
+pub struct SprintsWidget {
+    boards: Vec<Board>,
+    boards_state: ListState,
+    key_config: KeyConfig,
+    sprints: Vec<Sprint>,
+    sprints_focused: bool,
+    sprints_state: TableState,
+    pub chosen_sprint: Option<Sprint>,
+    pub load_sprints: bool,
+}
+
+impl SprintsWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([Constraint::Length(1), Constraint::Min(1)].as_ref())
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let msg = vec![
+            Span::raw("Press "),
+            Span::styled("ESC", bold),
+            Span::raw(" to exit, "),
+            Span::styled("Enter", bold),
+            Span::raw(" to load the board's sprints or the sprint's tickets, "),
+            Span::styled("h/l", bold),
+            Span::raw(" to switch between boards and sprints."),
+        ];
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(Style::default().add_modifier(Modifier::UNDERLINED));
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(30), Constraint::Percentage(70)].as_ref())
+            .split(chunks[1]);
+
+        let board_items: Vec<ListItem> = self
+            .boards
+            .iter()
+            .map(|board| ListItem::new(format!("{} ({})", board.name, board.board_type)))
+            .collect();
+        let boards = List::new(board_items)
+            .block(draw_block_style(!self.sprints_focused, "Boards"))
+            .highlight_style(draw_highlight_style());
+        f.render_stateful_widget(boards, main_chunks[0], &mut self.boards_state);
+
+        let headers = Row::new(["Sprint", "State", "Start", "End", "Goal"]);
+        let rows = self.sprints.iter().map(|sprint| {
+            Row::new([
+                Cell::from(sprint.name.as_str()),
+                Cell::from(sprint.state.as_str()),
+                Cell::from(sprint_date(&sprint.start_date)),
+                Cell::from(sprint_date(&sprint.end_date)),
+                Cell::from(sprint.goal.as_deref().unwrap_or_default()),
+            ])
+        });
+        let sprints = Table::new(rows)
+            .header(headers)
+            .block(draw_block_style(self.sprints_focused, "Sprints"))
+            .highlight_style(draw_highlight_style())
+            .widths(&[
+                Constraint::Percentage(25),
+                Constraint::Percentage(10),
+                Constraint::Percentage(12),
+                Constraint::Percentage(12),
+                Constraint::Percentage(41),
+            ]);
+        f.render_stateful_widget(sprints, main_chunks[1], &mut self.sprints_state);
+
+        Ok(())
+    }
+}
+
+impl SprintsWidget {
+    pub fn new(key_config: KeyConfig) -> Self {
+        Self {
+            boards: Vec::new(),
+            boards_state: ListState::default(),
+            chosen_sprint: None,
+            key_config,
+            load_sprints: false,
+            sprints: Vec::new(),
+            sprints_focused: false,
+            sprints_state: TableState::default(),
+        }
+    }
+
+    pub fn update_boards(&mut self, boards: Vec<Board>) {
+        self.boards = boards;
+        self.sprints.clear();
+        self.sprints_focused = false;
+        self.sprints_state.select(None);
+        let selected = if self.boards.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.boards_state.select(selected);
+    }
+
+    pub fn update_sprints(&mut self, sprints: Vec<Sprint>) {
+        self.sprints = sprints;
+        self.sprints_focused = !self.sprints.is_empty();
+        let selected = if self.sprints.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.sprints_state.select(selected);
+    }
+
+    pub fn selected_board(&self) -> Option<&Board> {
+        match self.boards_state.selected() {
+            Some(i) => self.boards.get(i),
+            None => None,
+        }
+    }
+
+    pub fn selected_sprint(&self) -> Option<&Sprint> {
+        match self.sprints_state.selected() {
+            Some(i) => self.sprints.get(i),
+            None => None,
+        }
+    }
+
+    fn move_selection(&mut self, forward: bool, line: usize) {
+        let (selected, len) = match self.sprints_focused {
+            true => (self.sprints_state.selected(), self.sprints.len()),
+            false => (self.boards_state.selected(), self.boards.len()),
+        };
+        if len == 0 {
+            return;
+        }
+        let i = selected.map(|i| match forward {
+            true => (i + line).min(len - 1),
+            false => i.saturating_sub(line),
+        });
+        match self.sprints_focused {
+            true => self.sprints_state.select(i),
+            false => self.boards_state.select(i),
+        }
+    }
+}
+
+impl SprintsWidget {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if key == self.key_config.scroll_down || key == self.key_config.move_down {
+            self.move_selection(true, 1);
+        } else if key == self.key_config.scroll_up || key == self.key_config.move_up {
+            self.move_selection(false, 1);
+        } else if key == self.key_config.scroll_down_multiple_lines {
+            self.move_selection(true, 10);
+        } else if key == self.key_config.scroll_up_multiple_lines {
+            self.move_selection(false, 10);
+        } else if key == self.key_config.scroll_left || key == self.key_config.move_left {
+            self.sprints_focused = false;
+        } else if key == self.key_config.scroll_right || key == self.key_config.move_right {
+            self.sprints_focused = !self.sprints.is_empty();
+        } else if key == self.key_config.enter {
+            match self.sprints_focused {
+                true => self.chosen_sprint = self.selected_sprint().cloned(),
+                false => self.load_sprints = self.selected_board().is_some(),
+            }
+        } else {
+            return Ok(EventState::NotConsumed);
+        }
+        Ok(EventState::Consumed)
+    }
+}
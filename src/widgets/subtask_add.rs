@@ -0,0 +1,122 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::event::key::Key;
+
+use super::{draw_block_style, EventState};
+
+/// Popup collecting one subtask summary per line.
+pub struct SubtaskAdd {
+    input: String,
+    parent_key: String,
+    pub push_subtasks: bool,
+    pub summaries: Vec<String>,
+}
+
+impl SubtaskAdd {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let width = 80;
+        let height = 20;
+        let area = Rect::new(
+            (f.size().width.saturating_sub(width)) / 2,
+            (f.size().height.saturating_sub(height)) / 2,
+            width.min(f.size().width),
+            height.min(f.size().height),
+        );
+        f.render_widget(Clear, area);
+        let title = format!("New subtasks of {}", self.parent_key);
+        f.render_widget(draw_block_style(true, &title), area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Min(3),
+                    Constraint::Length(3),
+                ]
+                .as_ref(),
+            )
+            .split(area);
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let msg = vec![
+            Span::raw("Press "),
+            Span::styled("Enter", bold),
+            Span::raw(" to add a line, "),
+            Span::styled("Enter", bold),
+            Span::raw(" on an empty line to create them, "),
+            Span::styled("Esc", bold),
+            Span::raw(" to cancel."),
+        ];
+        f.render_widget(Paragraph::new(Text::from(Spans::from(msg))), chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .summaries
+            .iter()
+            .map(|summary| ListItem::new(summary.as_str()))
+            .collect();
+        let list =
+            List::new(items).block(Block::default().borders(Borders::ALL).title("Summaries"));
+        f.render_widget(list, chunks[1]);
+
+        let input = Paragraph::new(self.input.as_ref())
+            .style(Style::default().fg(Color::Yellow))
+            .block(Block::default().borders(Borders::ALL).title("Summary"));
+        f.render_widget(input, chunks[2]);
+        f.set_cursor(chunks[2].x + self.input.len() as u16 + 1, chunks[2].y + 1);
+
+        Ok(())
+    }
+}
+
+impl SubtaskAdd {
+    pub fn new() -> Self {
+        Self {
+            input: String::new(),
+            parent_key: String::new(),
+            push_subtasks: false,
+            summaries: Vec::new(),
+        }
+    }
+
+    pub fn open(&mut self, parent_key: &str) {
+        self.input.clear();
+        self.parent_key = parent_key.to_string();
+        self.push_subtasks = false;
+        self.summaries.clear();
+    }
+}
+
+impl SubtaskAdd {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        match key {
+            Key::Char(c) => self.input.push(c),
+            Key::Backspace => {
+                // Backspace on an empty line takes the previous line back for editing.
+                if self.input.is_empty() {
+                    self.input = self.summaries.pop().unwrap_or_default();
+                } else {
+                    self.input.pop();
+                }
+            }
+            Key::Enter => {
+                let summary = self.input.trim().to_string();
+                self.input.clear();
+                if !summary.is_empty() {
+                    self.summaries.push(summary);
+                } else {
+                    self.push_subtasks = !self.summaries.is_empty();
+                }
+            }
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+}
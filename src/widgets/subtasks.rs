@@ -0,0 +1,139 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    widgets::{Cell, Clear, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    config::KeyConfig,
+    event::key::Key,
+    jira::tickets::{LinkInwardOutwardParent, TicketData},
+};
+
+use super::{commands::CommandInfo, draw_block_style, draw_highlight_style, Component, EventState};
+
+#[derive(Debug)]
+pub struct SubtasksWidget {
+    jira_domain: String,
+    key_config: KeyConfig,
+    state: TableState,
+    pub subtasks: Vec<LinkInwardOutwardParent>,
+}
+
+impl SubtasksWidget {
+    pub fn draw<B: Backend>(
+        &mut self,
+        f: &mut Frame<B>,
+        focused: bool,
+        rect: Rect,
+        selected_ticket: Option<&TicketData>,
+    ) -> anyhow::Result<()> {
+        f.render_widget(Clear, rect);
+        let ticket = match selected_ticket {
+            None => return Ok(()),
+            Some(ticket_data) => ticket_data,
+        };
+
+        if !focused {
+            self.state.select(None)
+        }
+        if focused && self.selected().is_none() && !ticket.fields.subtasks.is_empty() {
+            self.state.select(Some(0))
+        }
+
+        let title = "Subtasks";
+        let header_cells = ["Key", "Summary", "Priority", "Status"];
+        let headers = Row::new(header_cells);
+        self.subtasks = ticket.fields.subtasks.clone();
+        let rows = self.subtasks.iter().map(|subtask| {
+            let priority = match &subtask.fields.priority {
+                Some(i) => i.name.as_str(),
+                _ => "",
+            };
+            Row::new([
+                Cell::from(subtask.key.as_str()),
+                Cell::from(subtask.fields.summary.as_str()),
+                Cell::from(priority),
+                Cell::from(subtask.fields.status.name.as_str()),
+            ])
+        });
+        let table = Table::new(rows)
+            .header(headers)
+            .block(draw_block_style(focused, title))
+            .highlight_style(draw_highlight_style())
+            .widths(&[
+                Constraint::Percentage(20),
+                Constraint::Percentage(45),
+                Constraint::Percentage(15),
+                Constraint::Percentage(20),
+            ]);
+
+        f.render_stateful_widget(table, rect, &mut self.state);
+
+        Ok(())
+    }
+}
+
+impl SubtasksWidget {
+    pub fn new(key_config: KeyConfig, jira_domain: &str) -> Self {
+        Self {
+            jira_domain: jira_domain.to_string(),
+            key_config,
+            state: TableState::default(),
+            subtasks: vec![],
+        }
+    }
+
+    pub fn next(&mut self, line: usize) {
+        if self.subtasks.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.subtasks.len() - 1));
+        self.state.select(i);
+    }
+
+    pub fn previous(&mut self, line: usize) {
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
+        self.state.select(i);
+    }
+
+    pub fn selected(&self) -> Option<&LinkInwardOutwardParent> {
+        match self.state.selected() {
+            Some(i) => self.subtasks.get(i),
+            None => None,
+        }
+    }
+
+    pub fn open_browser(&self) -> anyhow::Result<()> {
+        if let Some(subtask) = self.selected() {
+            let url = format!("{}/browse/{}", self.jira_domain, subtask.key);
+            open::that(url)?;
+        }
+        Ok(())
+    }
+}
+
+impl Component for SubtasksWidget {
+    fn commands(&self, _out: &mut Vec<CommandInfo>) {}
+
+    fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if key == self.key_config.scroll_down {
+            self.next(1);
+        } else if key == self.key_config.scroll_up {
+            self.previous(1);
+        } else if key == self.key_config.scroll_down_multiple_lines {
+            self.next(10);
+        } else if key == self.key_config.scroll_up_multiple_lines {
+            self.previous(10);
+        } else if key == self.key_config.open_browser {
+            self.open_browser()?;
+        } else {
+            return Ok(EventState::NotConsumed);
+        }
+        Ok(EventState::Consumed)
+    }
+}
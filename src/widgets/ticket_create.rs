@@ -0,0 +1,205 @@
+use serde_json::{Map, Value};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{
+    config::KeyConfig,
+    event::key::Key,
+    jira::{
+        auth::JiraClient,
+        fields::{CreateIssueType, FieldMeta},
+    },
+};
+
+use super::{draw_block_style, draw_highlight_style, field_form::FieldForm, EventState, InputMode};
+
+pub struct TicketCreateWidget {
+    issue_types_state: ListState,
+    key_config: KeyConfig,
+    pub form: FieldForm,
+    pub issue_types: Vec<CreateIssueType>,
+    pub load_fields: bool,
+    pub push_ticket: bool,
+}
+
+impl TicketCreateWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let chunk_constraints = [
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(5),
+        ]
+        .as_ref();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(chunk_constraints)
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let select_type_style = (
+            vec![
+                Span::raw("Press "),
+                Span::styled("ESC", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to exit, "),
+                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to select the issue type."),
+            ],
+            Style::default().add_modifier(Modifier::UNDERLINED),
+        );
+        let normal_mode_style = (
+            vec![
+                Span::raw("Press "),
+                Span::styled("ESC", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to go back, "),
+                Span::styled("e", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to edit field, "),
+                Span::styled("h/l", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to change value, "),
+                Span::styled("P", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to create ticket in jira."),
+            ],
+            Style::default().add_modifier(Modifier::UNDERLINED),
+        );
+        let edit_mode_style = (
+            vec![
+                Span::raw("Press "),
+                Span::styled("Esc/Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to stop editing."),
+            ],
+            Style::default(),
+        );
+        let (msg, style) = match (self.form.is_empty(), &self.form.input_mode) {
+            (true, _) => select_type_style,
+            (false, InputMode::Normal) => normal_mode_style,
+            (false, InputMode::Editing) => edit_mode_style,
+        };
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(style);
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        if self.form.is_empty() {
+            let list_items: Vec<ListItem> = self
+                .issue_types
+                .iter()
+                .map(|issue_type| ListItem::new(issue_type.name.as_str()))
+                .collect();
+            let list = List::new(list_items)
+                .block(draw_block_style(true, "Select issue type"))
+                .highlight_style(draw_highlight_style());
+            f.render_stateful_widget(list, chunks[1], &mut self.issue_types_state);
+            return Ok(());
+        }
+
+        let title = match self.selected_issue_type() {
+            Some(issue_type) => format!("Create {}", issue_type.name),
+            None => "Create ticket".to_string(),
+        };
+        self.form.draw(f, chunks[1], chunks[2], &title)?;
+
+        Ok(())
+    }
+}
+
+impl TicketCreateWidget {
+    pub fn new(key_config: KeyConfig) -> Self {
+        Self {
+            form: FieldForm::new(),
+            issue_types_state: ListState::default(),
+            key_config,
+            issue_types: Vec::new(),
+            load_fields: false,
+            push_ticket: false,
+        }
+    }
+
+    pub fn update(&mut self, issue_types: Vec<CreateIssueType>) {
+        self.clear();
+        self.issue_types = issue_types
+            .into_iter()
+            .filter(|issue_type| !issue_type.subtask)
+            .collect();
+        if !self.issue_types.is_empty() {
+            self.issue_types_state.select(Some(0));
+        }
+    }
+
+    pub fn update_fields(&mut self, fields: Vec<FieldMeta>) {
+        let fields = fields
+            .into_iter()
+            .filter(|field| !matches!(field.id(), "project" | "issuetype"))
+            .filter(|field| field.required || matches!(field.id(), "summary" | "description"))
+            .collect();
+        self.form.update(fields);
+    }
+
+    pub fn clear(&mut self) {
+        self.form.clear();
+    }
+
+    pub fn selected_issue_type(&self) -> Option<&CreateIssueType> {
+        match self.issue_types_state.selected() {
+            Some(i) => self.issue_types.get(i),
+            None => None,
+        }
+    }
+
+    pub fn field_values(&self, jira_client: &JiraClient) -> anyhow::Result<Map<String, Value>> {
+        self.form.values(jira_client)
+    }
+
+    fn next(state: &mut ListState, len: usize, line: usize) {
+        if len == 0 {
+            return;
+        }
+        let i = state.selected().map(|i| (i + line).min(len - 1));
+        state.select(i);
+    }
+
+    fn previous(state: &mut ListState, line: usize) {
+        let i = state.selected().map(|i| i.saturating_sub(line));
+        state.select(i);
+    }
+}
+
+impl TicketCreateWidget {
+    fn issue_type_key_event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if key == self.key_config.scroll_down || key == self.key_config.move_down {
+            Self::next(&mut self.issue_types_state, self.issue_types.len(), 1);
+            return Ok(EventState::Consumed);
+        } else if key == self.key_config.scroll_up || key == self.key_config.move_up {
+            Self::previous(&mut self.issue_types_state, 1);
+            return Ok(EventState::Consumed);
+        } else if key == self.key_config.enter {
+            self.load_fields = self.selected_issue_type().is_some();
+            return Ok(EventState::Consumed);
+        }
+        Ok(EventState::NotConsumed)
+    }
+
+    fn form_key_event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if self.form.input_mode == InputMode::Normal {
+            if key == self.key_config.esc {
+                self.clear();
+                return Ok(EventState::Consumed);
+            } else if key == Key::Char('P') {
+                self.push_ticket = true;
+                return Ok(EventState::Consumed);
+            }
+        }
+        Ok(self.form.event(key, &self.key_config))
+    }
+
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if self.form.is_empty() {
+            return self.issue_type_key_event(key);
+        }
+        self.form_key_event(key)
+    }
+}
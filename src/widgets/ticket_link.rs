@@ -0,0 +1,202 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{config::KeyConfig, event::key::Key, jira::tickets::LinkType};
+
+use super::{autocomplete::AutocompleteWidget, draw_block_style, draw_highlight_style, EventState};
+
+#[derive(Debug, Clone)]
+pub struct NewLink {
+    pub link_type: LinkType,
+    pub outward: bool,
+    pub target: String,
+}
+
+/// Picks a link type and direction, then completes the target key over cached tickets.
+pub struct TicketLinkWidget {
+    choosing_target: bool,
+    key_config: KeyConfig,
+    link_types: Vec<LinkType>,
+    outward: bool,
+    state: ListState,
+    target: AutocompleteWidget,
+    ticket_keys: Vec<String>,
+    pub submitted: Option<NewLink>,
+}
+
+impl TicketLinkWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([Constraint::Length(1), Constraint::Min(1)].as_ref())
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let msg = vec![
+            Span::raw("Press "),
+            Span::styled("ESC", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" to exit, "),
+            Span::styled("h/l", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" to switch direction, "),
+            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" to choose the target ticket."),
+        ];
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(Style::default().add_modifier(Modifier::UNDERLINED));
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        let list_items: Vec<ListItem> = self
+            .link_types
+            .iter()
+            .map(|link_type| {
+                let name = link_type.name.as_deref().unwrap_or_default();
+                ListItem::new(format!(
+                    "{:<20} this ticket {} ...",
+                    name,
+                    self.description(link_type)
+                ))
+            })
+            .collect();
+        let list = List::new(list_items)
+            .block(draw_block_style(!self.choosing_target, "Link type"))
+            .highlight_style(draw_highlight_style());
+        f.render_stateful_widget(list, chunks[1], &mut self.state);
+
+        if self.choosing_target {
+            self.target.draw(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TicketLinkWidget {
+    pub fn new(key_config: KeyConfig) -> Self {
+        Self {
+            choosing_target: false,
+            key_config,
+            link_types: Vec::new(),
+            outward: true,
+            state: ListState::default(),
+            submitted: None,
+            target: AutocompleteWidget::new(),
+            ticket_keys: Vec::new(),
+        }
+    }
+
+    pub fn open(&mut self, link_types: Vec<LinkType>, ticket_keys: Vec<String>) {
+        self.link_types = link_types;
+        self.ticket_keys = ticket_keys;
+        self.choosing_target = false;
+        self.outward = true;
+        self.submitted = None;
+        let selected = if self.link_types.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.state.select(selected);
+    }
+
+    fn description<'a>(&self, link_type: &'a LinkType) -> &'a str {
+        if self.outward {
+            &link_type.outward
+        } else {
+            &link_type.inward
+        }
+    }
+
+    pub fn selected(&self) -> Option<&LinkType> {
+        match self.state.selected() {
+            Some(i) => self.link_types.get(i),
+            None => None,
+        }
+    }
+
+    pub fn next(&mut self, line: usize) {
+        if self.link_types.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.link_types.len() - 1));
+        self.state.select(i);
+    }
+
+    pub fn previous(&mut self, line: usize) {
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
+        self.state.select(i);
+    }
+
+    fn open_target(&mut self) {
+        let title = match self.selected() {
+            Some(link_type) => format!("This ticket {} ...", self.description(link_type)),
+            None => return,
+        };
+        self.target.open(&title, self.ticket_keys.clone());
+        self.choosing_target = true;
+    }
+}
+
+impl TicketLinkWidget {
+    fn target_key_event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if self.target.event(key)?.is_consumed() {
+            if let Some(target) = self.target.submitted.take() {
+                // Candidates are "KEY summary", typed input is just the key.
+                let target = target
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+                    .to_uppercase();
+                if let Some(link_type) = self.selected() {
+                    self.submitted = Some(NewLink {
+                        link_type: link_type.clone(),
+                        outward: self.outward,
+                        target,
+                    });
+                }
+                self.choosing_target = false;
+            }
+            return Ok(EventState::Consumed);
+        }
+        if key == self.key_config.esc {
+            self.choosing_target = false;
+            return Ok(EventState::Consumed);
+        }
+        Ok(EventState::NotConsumed)
+    }
+
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if self.choosing_target {
+            return self.target_key_event(key);
+        }
+        if key == self.key_config.scroll_down || key == self.key_config.move_down {
+            self.next(1);
+        } else if key == self.key_config.scroll_up || key == self.key_config.move_up {
+            self.previous(1);
+        } else if key == self.key_config.scroll_down_multiple_lines {
+            self.next(10);
+        } else if key == self.key_config.scroll_up_multiple_lines {
+            self.previous(10);
+        } else if key == self.key_config.scroll_left
+            || key == self.key_config.scroll_right
+            || key == self.key_config.move_left
+            || key == self.key_config.move_right
+        {
+            self.outward = !self.outward;
+        } else if key == self.key_config.enter {
+            self.open_target();
+        } else {
+            return Ok(EventState::NotConsumed);
+        }
+        Ok(EventState::Consumed)
+    }
+}
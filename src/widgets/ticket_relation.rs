@@ -128,7 +128,7 @@ impl RelationWidget {
         let i = self
             .state
             .selected()
-            .map(|i| if i <= line { 0 } else { i - line });
+            .map(|i| i.saturating_sub(line));
 
         self.state.select(i);
     }
@@ -1,35 +1,30 @@
-use log::{debug, trace};
-use anyhow::anyhow;
 use tui::{
     backend::Backend,
-    layout::{Rect, Direction, Layout, Constraint},
-    style::{Style, Color},
-    text::{Span, Spans},
-    widgets::{Clear, List, ListItem, ListState, Borders, Wrap, Paragraph, Block},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Clear, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
 use crate::{
     config::KeyConfig,
     event::key::Key,
-    jira::tickets::{CustomFieldAllowedValues, TicketTransition, TicketTransitions},
+    jira::tickets::{TicketTransition, TicketTransitions},
 };
 
-use super::{commands::CommandInfo, draw_block_style, draw_highlight_style, Component, EventState, InputMode};
+use super::{
+    commands::CommandInfo, draw_block_style, draw_highlight_style, field_form::FieldForm,
+    Component, EventState, InputMode,
+};
 
-#[derive(Debug)]
 pub struct TransitionWidget {
-    draw_list_float_screen: Option<bool>,
-    float_screen_list_state: ListState,
-    float_screen_list: Option<Vec<CustomFieldAllowedValues>>,
-    focus_float_screen: Option<bool>,
     key_config: KeyConfig,
     state: ListState,
-    pub comment_float_screen: String,
+    /// Fields of the chosen transition's screen, empty while picking the transition.
+    pub form: FieldForm,
     pub push_transition: bool,
-    pub push_transition_reason: Option<String>,
     pub transitions: Vec<TicketTransition>,
-    pub input_mode: InputMode,
 }
 
 impl TransitionWidget {
@@ -40,16 +35,17 @@ impl TransitionWidget {
         rect: Rect,
     ) -> anyhow::Result<()> {
         f.render_widget(Clear, rect);
-        let title = "Transition";
-        let mut list_items: Vec<ListItem> = Vec::new();
-        for c in &self.transitions {
-            if let Some(name) = c.name.as_ref() {
-                debug!("Transition name: {:?}", name);
-                list_items
-                    .push(ListItem::new(vec![Spans::from(Span::raw(name))]).style(Style::default()))
-            }
+        if !self.form.is_empty() {
+            return self.draw_form(f);
         }
 
+        let title = "Transition";
+        let list_items: Vec<ListItem> = self
+            .transitions
+            .iter()
+            .filter_map(|t| t.name.as_ref())
+            .map(|name| ListItem::new(vec![Spans::from(Span::raw(name))]))
+            .collect();
         let list = List::new(list_items)
             .block(draw_block_style(focused, title))
             .highlight_style(draw_highlight_style());
@@ -66,130 +62,87 @@ impl TransitionWidget {
         f.render_widget(Clear, area);
         f.render_stateful_widget(list, area, &mut self.state);
 
-        trace!(
-            "Switch to draw float screen is {:?}",
-            self.draw_list_float_screen
-        );
-        if self.draw_list_float_screen == Some(true) {
-            let mut allowed_values: Vec<CustomFieldAllowedValues> = Vec::new();
-            let transition = match self.selected_transition() {
-                Some(t) => t,
-                None => return Err(anyhow!("Failed to retrieve transition"))
-            };
-            debug!("{:?}", transition);
-            let fields = match &transition.fields {
-                Some(f) => f, 
-                None => return Err(anyhow!("Failed to retrieve fields"))
-            };
-            for f in &fields.values {
-                debug!("float screen schema {:?}", &f.1.schema);
-                match &f.1.schema.custom {
-                    Some(c) => {
-                        if !c.ends_with(":select") {
-                            continue;
-                        }
-                        if let Some(v) = &f.1.allowed_values {
-                            allowed_values = v.clone() 
-                        }
-                    }
-                    None => {
-                        continue;
-                    }
-                }
-            }
-            self.float_screen_list = Some(allowed_values.clone());
-            f.render_widget(Clear, area);
-            return self.draw_select_screen(f);
-        }
-
         Ok(())
     }
 
-    fn draw_select_screen<B: Backend>(
-        &mut self,
-        f: &mut Frame<B>,
-    ) -> anyhow::Result<()> {
-        let chunk_constrains = [
+    fn draw_form<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let chunk_constraints = [
+            Constraint::Length(1),
             Constraint::Min(1),
             Constraint::Length(5),
         ];
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .margin(2)
-            .constraints(chunk_constrains)
+            .constraints(chunk_constraints)
             .split(f.size());
-        self.focus_float_screen = Some(true);
-        let title = "Select Transition Reason";
-        let mut list_items: Vec<ListItem> = Vec::new();
-        let select_list = match self.float_screen_list.clone() {
-            Some(s) => s,
-            None => return Err(anyhow!("Failed to retrieve float screen list"))
+        f.render_widget(Clear, f.size());
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let (msg, style) = match self.form.input_mode {
+            InputMode::Normal => (
+                vec![
+                    Span::raw("Press "),
+                    Span::styled("ESC", bold),
+                    Span::raw(" to go back, "),
+                    Span::styled("e", bold),
+                    Span::raw(" to edit field, "),
+                    Span::styled("h/l", bold),
+                    Span::raw(" to change value, "),
+                    Span::styled("P", bold),
+                    Span::raw(" to transition the ticket."),
+                ],
+                Style::default().add_modifier(Modifier::UNDERLINED),
+            ),
+            InputMode::Editing => (
+                vec![
+                    Span::raw("Press "),
+                    Span::styled("Esc/Enter", bold),
+                    Span::raw(" to stop editing."),
+                ],
+                Style::default(),
+            ),
         };
-        for allowed_value in select_list {
-            let value = allowed_value.value;
-            list_items
-                .push(ListItem::new(vec![Spans::from(Span::raw(value))]).style(Style::default()))
-        }
-        let list = List::new(list_items)
-            .block(draw_block_style(true, title))
-            .highlight_style(draw_highlight_style());
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(style);
+        f.render_widget(Paragraph::new(text), chunks[0]);
 
-        let comment = self.comment_float_screen.clone();
-        let input = Paragraph::new(comment)
-            .wrap(Wrap { trim: true })
-            .style(match self.input_mode {
-                InputMode::Normal => Style::default(),
-                InputMode::Editing => Style::default().fg(Color::Yellow),
-            })
-            .block(Block::default().borders(Borders::ALL).title("Comment"));
-        f.render_widget(Clear, chunks[1]);
-        f.render_widget(input, chunks[1]);
-        f.render_stateful_widget(list, chunks[0], &mut self.float_screen_list_state);
-
-        Ok(())
+        let title = match self.selected_transition().and_then(|t| t.name.as_ref()) {
+            Some(name) => format!("Transition: {}", name),
+            None => "Transition".to_string(),
+        };
+        self.form.draw(f, chunks[1], chunks[2], &title)
     }
 }
 
 impl TransitionWidget {
     pub fn new(transitions: Vec<TicketTransition>, key_config: KeyConfig) -> Self {
-        let mut float_screen_list_state = ListState::default();
-        float_screen_list_state.select(Some(0));
         let mut state = ListState::default();
         if !transitions.is_empty() {
             state.select(Some(0));
         }
         Self {
-            draw_list_float_screen: None,
-            comment_float_screen: String::new(),
-            float_screen_list_state,
-            float_screen_list: None,
-            focus_float_screen: None,
-            input_mode: InputMode::Normal,
+            form: FieldForm::new(),
             key_config,
             push_transition: false,
-            push_transition_reason: None,
             state,
-            transitions: Vec::new(),
+            transitions,
         }
     }
 
     pub fn next(&mut self, line: usize) {
-        let i = match self.state.selected() {
-            Some(i) if i + line >= self.transitions.len() => Some(self.transitions.len() - 1),
-            Some(i) => Some(i + line),
-            None => None,
-        };
-
+        if self.transitions.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.transitions.len() - 1));
         self.state.select(i);
     }
 
     pub fn previous(&mut self, line: usize) {
-        let i = match self.state.selected() {
-            Some(i) if i <= line => Some(0),
-            Some(i) => Some(i - line),
-            None => None,
-        };
-
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
         self.state.select(i);
     }
 
@@ -216,132 +169,42 @@ impl TransitionWidget {
 
     pub fn update(&mut self, transitions: &TicketTransitions) {
         self.transitions = transitions.transitions.clone();
-        if !transitions.transitions.is_empty() {
-            self.state.select(Some(0));
-        }
-    }
-
-    pub fn check_transition_floating_screen(&mut self) -> bool {
-        match self.selected_transition() {
-            None => false,
-            Some(t) => {
-                if t.has_screen.unwrap_or_else(|| false) {
-                    self.draw_list_float_screen = Some(true);
-                    return true;
-                }
-                false
-            }
-        }
-    }
-}
-
-impl TransitionWidget {
-    pub fn next_reason(&mut self, line: usize) {
-        let i = match self.float_screen_list_state.selected() {
-            Some(i) if i + line >= self.float_screen_list.clone().unwrap().len() => {
-                Some(self.float_screen_list.clone().unwrap().len() - 1)
-            }
-            Some(i) => Some(i + line),
-            None => None,
+        self.form.clear();
+        self.push_transition = false;
+        let selected = if self.transitions.is_empty() {
+            None
+        } else {
+            Some(0)
         };
-
-        self.float_screen_list_state.select(i);
+        self.state.select(selected);
     }
 
-    pub fn previous_reason(&mut self, line: usize) {
-        let i = match self.float_screen_list_state.selected() {
-            Some(i) if i <= line => Some(0),
-            Some(i) => Some(i - line),
-            None => None,
+    /// Opens the form of the selected transition, or pushes it straight away without fields.
+    fn choose_transition(&mut self) {
+        let fields = match self.selected_transition() {
+            None => return,
+            Some(t) => t.form_fields(),
         };
-
-        self.float_screen_list_state.select(i);
-    }
-
-    pub fn go_to_top_reason(&mut self) {
-        if self.float_screen_list.clone().unwrap().is_empty() {
-            return;
-        }
-        self.float_screen_list_state.select(Some(0));
-    }
-
-    pub fn go_to_bottom_reason(&mut self) {
-        if self.float_screen_list.clone().unwrap().is_empty() {
-            return;
-        }
-        self.float_screen_list_state
-            .select(Some(self.transitions.len() - 1));
-    }
-
-    pub fn selected_transition_reason(&self) -> Option<&CustomFieldAllowedValues> {
-        match self.float_screen_list_state.selected() {
-            Some(i) => self.float_screen_list.as_ref().unwrap().get(i),
-            None => None,
+        if fields.is_empty() {
+            self.push_transition = true;
+        } else {
+            self.form.update(fields);
         }
     }
 }
 
 impl TransitionWidget {
-    fn float_screen_event(&mut self, key: Key) -> anyhow::Result<EventState> {
-        if self.input_mode == InputMode::Editing {
-            match key {
-                Key::Char(c) => {
-                    let mut comment = self.comment_float_screen.clone();
-                    comment.push(c);
-                    self.comment_float_screen = comment;
-                    return Ok(EventState::Consumed)
-                }
-                Key::Backspace => {
-                    let mut comment = self.comment_float_screen.clone();
-                    comment.pop();
-                    self.comment_float_screen = comment;
-                    return Ok(EventState::Consumed)
-                }
-                Key::Esc => {
-                    self.input_mode = InputMode::Normal;
-                    return Ok(EventState::Consumed)
-                }
-                // _ => self.movement(key), // _ => return Ok(EventState::NotConsumed)
-                _ => {}
-            }
-        }
-        if key == self.key_config.scroll_down {
-            self.next_reason(1);
-            return Ok(EventState::Consumed);
-        } else if key == self.key_config.scroll_up {
-            self.previous_reason(1);
-            return Ok(EventState::Consumed);
-        } else if key == self.key_config.scroll_down_multiple_lines {
-            self.next_reason(10);
-            return Ok(EventState::Consumed);
-        } else if key == self.key_config.scroll_up_multiple_lines {
-            self.previous_reason(10);
-            return Ok(EventState::Consumed);
-        } else if key == self.key_config.scroll_to_bottom {
-            self.go_to_bottom_reason();
-            return Ok(EventState::Consumed);
-        } else if key == self.key_config.scroll_to_top {
-            self.go_to_top_reason();
-            return Ok(EventState::Consumed);
-        } else if key == self.key_config.esc {
-            self.draw_list_float_screen = Some(false);
-            return Ok(EventState::Consumed);
-        } else if key == self.key_config.next {
-            self.input_mode = InputMode::Editing;
-            return Ok(EventState::Consumed);
-        } else if key == self.key_config.enter {
-            // TODO: Add comment push based on transition selection
-            match self.selected_transition_reason() {
-                Some(i) => {
-                    self.push_transition_reason = Some(i.value.clone());
-                    self.draw_list_float_screen = Some(false);
-                    self.push_transition = true
-                }
-                None => {}
+    fn form_event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if self.form.input_mode == InputMode::Normal {
+            if key == self.key_config.esc {
+                self.form.clear();
+                return Ok(EventState::Consumed);
+            } else if key == Key::Char('P') {
+                self.push_transition = true;
+                return Ok(EventState::Consumed);
             }
-            return Ok(EventState::Consumed);
         }
-        Ok(EventState::NotConsumed)
+        Ok(self.form.event(key, &self.key_config))
     }
 }
 
@@ -349,33 +212,26 @@ impl Component for TransitionWidget {
     fn commands(&self, _out: &mut Vec<CommandInfo>) {}
 
     fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
-        if self.draw_list_float_screen == Some(true) {
-            return self.float_screen_event(key);
+        if !self.form.is_empty() {
+            return self.form_event(key);
         }
         if key == self.key_config.scroll_down {
             self.next(1);
-            return Ok(EventState::Consumed);
         } else if key == self.key_config.scroll_up {
             self.previous(1);
-            return Ok(EventState::Consumed);
         } else if key == self.key_config.scroll_down_multiple_lines {
             self.next(10);
-            return Ok(EventState::Consumed);
         } else if key == self.key_config.scroll_up_multiple_lines {
             self.previous(10);
-            return Ok(EventState::Consumed);
         } else if key == self.key_config.scroll_to_bottom {
             self.go_to_bottom();
-            return Ok(EventState::Consumed);
         } else if key == self.key_config.scroll_to_top {
             self.go_to_top();
-            return Ok(EventState::Consumed);
         } else if key == self.key_config.enter {
-            if !self.check_transition_floating_screen() {
-                self.push_transition = true;
-            }
-            return Ok(EventState::Consumed);
+            self.choose_transition();
+        } else {
+            return Ok(EventState::NotConsumed);
         }
-        Ok(EventState::NotConsumed)
+        Ok(EventState::Consumed)
     }
 }
@@ -0,0 +1,303 @@
+use std::collections::{HashMap, HashSet};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    config::KeyConfig,
+    event::key::Key,
+    jira::tickets::{Status, TicketData},
+};
+
+use super::{draw_block_style, draw_highlight_style, EventState};
+
+fn is_done(status: &Status) -> bool {
+    match &status.status_category {
+        Some(category) => category.key == "done",
+        None => false,
+    }
+}
+
+/// A visible line of the tree.
+struct TreeRow {
+    depth: usize,
+    key: String,
+}
+
+/// Epic, stories and subtasks as a collapsible tree, children loaded on expand.
+pub struct TicketTreeWidget {
+    children: HashMap<String, Vec<TicketData>>,
+    expanded: HashSet<String>,
+    key_config: KeyConfig,
+    root: Option<TicketData>,
+    rows: Vec<TreeRow>,
+    state: TableState,
+    tickets: HashMap<String, TicketData>,
+    pub chosen_ticket: Option<String>,
+    pub load_children: Option<String>,
+    pub reload_children: Option<String>,
+}
+
+impl TicketTreeWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([Constraint::Length(1), Constraint::Min(1)].as_ref())
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let msg = vec![
+            Span::raw("Press "),
+            Span::styled("ESC", bold),
+            Span::raw(" to exit, "),
+            Span::styled("l", bold),
+            Span::raw(" to expand, "),
+            Span::styled("h", bold),
+            Span::raw(" to collapse, "),
+            Span::styled("Enter", bold),
+            Span::raw(" to open the ticket details, "),
+            Span::styled(self.key_config.reset.to_string(), bold),
+            Span::raw(" to reload the children from Jira."),
+        ];
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(Style::default().add_modifier(Modifier::UNDERLINED));
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        let title = match &self.root {
+            Some(root) => format!("{} hierarchy", root.key),
+            None => "Hierarchy".to_string(),
+        };
+        let headers = Row::new(["Key", "Summary", "Type", "Status", "Done"]);
+        let rows: Vec<Row> = self
+            .rows
+            .iter()
+            .filter_map(|row| {
+                let ticket = self.tickets.get(&row.key)?;
+                let marker = match self.children.get(&row.key) {
+                    Some(children) if children.is_empty() => " ",
+                    Some(_) if self.expanded.contains(&row.key) => "▾",
+                    _ => "▸",
+                };
+                let progress = match self.progress(&row.key) {
+                    Some((done, total)) => format!("{}/{}", done, total),
+                    None => String::new(),
+                };
+                Some(Row::new([
+                    Cell::from(format!(
+                        "{}{} {}",
+                        "  ".repeat(row.depth),
+                        marker,
+                        ticket.key
+                    )),
+                    Cell::from(ticket.fields.summary.clone()),
+                    Cell::from(ticket.fields.issuetype.name.clone()),
+                    Cell::from(ticket.fields.status.name.clone()),
+                    Cell::from(progress),
+                ]))
+            })
+            .collect();
+        let table = Table::new(rows)
+            .header(headers)
+            .block(draw_block_style(true, &title))
+            .highlight_style(draw_highlight_style())
+            .widths(&[
+                Constraint::Percentage(20),
+                Constraint::Percentage(45),
+                Constraint::Percentage(10),
+                Constraint::Percentage(15),
+                Constraint::Percentage(10),
+            ]);
+        f.render_stateful_widget(table, chunks[1], &mut self.state);
+
+        Ok(())
+    }
+}
+
+impl TicketTreeWidget {
+    pub fn new(key_config: KeyConfig) -> Self {
+        Self {
+            children: HashMap::new(),
+            chosen_ticket: None,
+            expanded: HashSet::new(),
+            key_config,
+            load_children: None,
+            reload_children: None,
+            root: None,
+            rows: Vec::new(),
+            state: TableState::default(),
+            tickets: HashMap::new(),
+        }
+    }
+
+    pub fn open(&mut self, root: TicketData) {
+        self.children.clear();
+        self.expanded.clear();
+        self.tickets.clear();
+        self.tickets.insert(root.key.clone(), root.clone());
+        self.root = Some(root);
+        self.state.select(Some(0));
+        self.build_rows();
+    }
+
+    /// Records the children of `key` and expands it.
+    pub fn set_children(&mut self, key: &str, children: Vec<TicketData>) {
+        for child in &children {
+            self.tickets.insert(child.key.clone(), child.clone());
+        }
+        self.children.insert(key.to_string(), children);
+        self.expanded.insert(key.to_string());
+        self.build_rows();
+    }
+
+    pub fn selected_key(&self) -> Option<&str> {
+        let row = self.rows.get(self.state.selected()?)?;
+        Some(row.key.as_str())
+    }
+
+    /// Done and total counts over the descendants of `key`. Tickets whose children aren't
+    /// loaded count their sub-tasks, which Jira sends along with the ticket.
+    fn progress(&self, key: &str) -> Option<(usize, usize)> {
+        let children = match self.children.get(key) {
+            Some(children) => children,
+            None => {
+                let subtasks = &self.tickets.get(key)?.fields.subtasks;
+                if subtasks.is_empty() {
+                    return None;
+                }
+                let done = subtasks
+                    .iter()
+                    .filter(|subtask| is_done(&subtask.fields.status))
+                    .count();
+                return Some((done, subtasks.len()));
+            }
+        };
+        if children.is_empty() {
+            return None;
+        }
+        let mut done = 0;
+        let mut total = 0;
+        for child in children {
+            total += 1;
+            if is_done(&child.fields.status) {
+                done += 1;
+            }
+            if let Some((child_done, child_total)) = self.progress(&child.key) {
+                done += child_done;
+                total += child_total;
+            }
+        }
+        Some((done, total))
+    }
+
+    fn build_rows(&mut self) {
+        self.rows.clear();
+        let root = match &self.root {
+            Some(root) => root.key.clone(),
+            None => return,
+        };
+        let mut stack = vec![(0, root)];
+        while let Some((depth, key)) = stack.pop() {
+            if self.expanded.contains(&key) {
+                if let Some(children) = self.children.get(&key) {
+                    for child in children.iter().rev() {
+                        stack.push((depth + 1, child.key.clone()));
+                    }
+                }
+            }
+            self.rows.push(TreeRow { depth, key });
+        }
+        let selected = self
+            .state
+            .selected()
+            .map(|i| i.min(self.rows.len().saturating_sub(1)));
+        self.state.select(selected);
+    }
+
+    fn expand(&mut self) {
+        let key = match self.selected_key() {
+            Some(key) => key.to_string(),
+            None => return,
+        };
+        if self.children.contains_key(&key) {
+            self.expanded.insert(key);
+            self.build_rows();
+        } else {
+            self.load_children = Some(key);
+        }
+    }
+
+    /// Collapses the selected node, or moves to its parent when it is already collapsed.
+    fn collapse(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => i,
+            None => return,
+        };
+        let (depth, key) = match self.rows.get(i) {
+            Some(row) => (row.depth, row.key.clone()),
+            None => return,
+        };
+        if self.expanded.remove(&key) {
+            self.build_rows();
+            return;
+        }
+        let parent = self.rows[..i].iter().rposition(|row| row.depth < depth);
+        if parent.is_some() {
+            self.state.select(parent);
+        }
+    }
+
+    fn reload(&mut self) {
+        if let Some(key) = self.selected_key() {
+            self.reload_children = Some(key.to_string());
+        }
+    }
+
+    pub fn next(&mut self, line: usize) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.rows.len() - 1));
+        self.state.select(i);
+    }
+
+    pub fn previous(&mut self, line: usize) {
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
+        self.state.select(i);
+    }
+}
+
+impl TicketTreeWidget {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if key == self.key_config.scroll_down || key == self.key_config.move_down {
+            self.next(1);
+        } else if key == self.key_config.scroll_up || key == self.key_config.move_up {
+            self.previous(1);
+        } else if key == self.key_config.scroll_down_multiple_lines {
+            self.next(10);
+        } else if key == self.key_config.scroll_up_multiple_lines {
+            self.previous(10);
+        } else if key == self.key_config.scroll_right || key == self.key_config.move_right {
+            self.expand();
+        } else if key == self.key_config.scroll_left || key == self.key_config.move_left {
+            self.collapse();
+        } else if key == self.key_config.reset {
+            self.reload();
+        } else if key == self.key_config.enter {
+            self.chosen_ticket = self.selected_key().map(|k| k.to_string());
+        } else {
+            return Ok(EventState::NotConsumed);
+        }
+        Ok(EventState::Consumed)
+    }
+}
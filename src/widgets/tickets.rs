@@ -1,21 +1,26 @@
 use crate::{config::KeyConfig, event::key::Key, jira::tickets::TicketData};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use html2md::parse_html;
 use tui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::Text,
     widgets::{Cell, Clear, ListState, Paragraph, Row, Table, TableState, Wrap},
     Frame,
 };
 
 use super::{
+    adf::render_adf,
     commands::{CommandInfo, CommandText},
     draw_block_style, draw_highlight_style, Component, EventState,
 };
 
 #[derive(Debug, Clone, Copy)]
 pub enum Action {
+    MarkAll,
+    MarkRange,
     OpenBrowser,
     Next(usize),
     Previous(usize),
@@ -23,12 +28,21 @@ pub enum Action {
     First,
     ScrollDownDescription(u16),
     ScrollUpDescription(u16),
+    ToggleMark,
 }
 
 impl Action {
     pub fn to_command_text(self, key: Key) -> CommandText {
         const CMD_GROUP_GENERAL: &str = "-- General --";
         match self {
+            Self::MarkAll => CommandText::new(
+                format!("Mark all tickets, or clear the marks [{key}]"),
+                CMD_GROUP_GENERAL,
+            ),
+            Self::MarkRange => CommandText::new(
+                format!("Start/end marking a range of tickets [{key}]"),
+                CMD_GROUP_GENERAL,
+            ),
             Self::OpenBrowser => {
                 CommandText::new(format!("Open Ticket in browser [{key}]"), CMD_GROUP_GENERAL)
             }
@@ -46,6 +60,10 @@ impl Action {
                 format!("Scroll up description {line} [{key}]"),
                 CMD_GROUP_GENERAL,
             ),
+            Self::ToggleMark => CommandText::new(
+                format!("Mark ticket for bulk transition, assign, label or comment [{key}]"),
+                CMD_GROUP_GENERAL,
+            ),
         }
     }
 }
@@ -53,10 +71,16 @@ impl Action {
 #[derive(Debug)]
 pub struct TicketWidget {
     jira_domain: String,
+    /// Keys of the tickets marked for a bulk operation.
+    marked: HashSet<String>,
+    /// Where the range being marked started, while marking one.
+    range_start: Option<usize>,
     state: TableState,
     scroll: u16,
-    ticket_description: Option<String>,
+    ticket_description: Option<Text<'static>>,
     pub tickets: Vec<TicketData>,
+    /// Replaces the "Tickets" title when the list isn't the project's tickets, e.g. a sprint.
+    pub title: Option<String>,
     pub key_mappings: HashMap<Key, Action>,
 }
 
@@ -68,14 +92,22 @@ impl TicketWidget {
         focused: bool,
         rect: Rect,
     ) -> anyhow::Result<()> {
-        let title = "Tickets";
+        let mut title = self.title.clone().unwrap_or_else(|| "Tickets".to_string());
+        if self.range_start.is_some() {
+            title += " -- MARK RANGE --";
+        } else if !self.marked.is_empty() {
+            title += &format!(" ({} marked)", self.marked_keys().len());
+        }
+        let range = self.marked_range();
 
         let header_cells = [
             "Key", "Priority", "Type", "Status", "Assignee", "Creator", "Reporter",
         ];
         let headers = Row::new(header_cells);
         let tickets = self.tickets.clone();
-        let rows = tickets.iter().map(|ticket| {
+        let rows = tickets.iter().enumerate().map(|(index, ticket)| {
+            let marked = self.marked.contains(&ticket.key)
+                || range.as_ref().is_some_and(|r| r.contains(&index));
             let assignee = match &ticket.fields.assignee {
                 Some(i) => i.display_name.as_str(),
                 _ => "Unassigned",
@@ -92,8 +124,13 @@ impl TicketWidget {
                 Some(i) => i.name.as_str(),
                 _ => "",
             };
+            let key = if marked {
+                format!("● {}", ticket.key)
+            } else {
+                ticket.key.clone()
+            };
             let item = [
-                ticket.key.as_str(),
+                key.as_str(),
                 priority,
                 ticket.fields.issuetype.name.as_str(),
                 ticket.fields.status.name.as_str(),
@@ -107,12 +144,17 @@ impl TicketWidget {
                 .max()
                 .unwrap_or(0)
                 + 1;
-            let cells = item.iter().map(|c| Cell::from(*c));
-            Row::new(cells).height(height as u16)
+            let cells = item.iter().map(|c| Cell::from(c.to_string()));
+            let style = if marked {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            Row::new(cells).height(height as u16).style(style)
         });
         let table = Table::new(rows)
             .header(headers)
-            .block(draw_block_style(focused, title))
+            .block(draw_block_style(focused, &title))
             .highlight_style(draw_highlight_style())
             .widths(&[
                 Constraint::Percentage(15),
@@ -126,12 +168,25 @@ impl TicketWidget {
         match self.selected() {
             Some(ticket) => {
                 // let summary = ticket.fields.summary.clone();
-                let summary = format!("{:} - {:}", ticket.key, ticket.fields.summary.clone());
-                let description = ticket.rendered_fields.description.clone();
+                let mut summary =
+                    format!("{:} - {:}", ticket.key, ticket.fields.summary.clone());
+                if let Some(tracking) = &ticket.fields.timetracking {
+                    let none = "-";
+                    summary += &format!(
+                        "\nEstimate: {} | Remaining: {} | Logged: {}",
+                        tracking.original_estimate.as_deref().unwrap_or(none),
+                        tracking.remaining_estimate.as_deref().unwrap_or(none),
+                        tracking.time_spent.as_deref().unwrap_or(none),
+                    );
+                }
+                let description = match &ticket.fields.description {
+                    Some(adf) if adf.is_object() => render_adf(adf),
+                    _ => Text::from(parse_html(&ticket.rendered_fields.description)),
+                };
                 self.draw_description(f, focused, description_frame, summary, description)
             }
             None => {
-                self.draw_description(f, focused, description_frame, String::new(), String::new())
+                self.draw_description(f, focused, description_frame, String::new(), Text::default())
             }
         }?;
 
@@ -147,7 +202,7 @@ impl TicketWidget {
         focused: bool,
         rect: Rect,
         summary: String,
-        description: String,
+        description: Text<'static>,
     ) -> anyhow::Result<()> {
         f.render_widget(Clear, rect);
         let summary_title = "Summary";
@@ -156,8 +211,8 @@ impl TicketWidget {
         let text = match &self.ticket_description {
             Some(d) => d.clone(),
             None => {
-                self.ticket_description = Some(parse_html(&description));
-                parse_html(&description)
+                self.ticket_description = Some(description.clone());
+                description
             }
         };
 
@@ -168,7 +223,7 @@ impl TicketWidget {
         let paragraph = Paragraph::new(text)
             .block(draw_block_style(focused, title))
             .alignment(Alignment::Left)
-            .wrap(Wrap { trim: true })
+            .wrap(Wrap { trim: false })
             .scroll((self.scroll, 0));
 
         let main_chunks = Layout::default()
@@ -195,6 +250,9 @@ impl TicketWidget {
         let key_mappings = {
             let mut map = HashMap::new();
             map.insert(key_config.open_browser, Action::OpenBrowser);
+            map.insert(key_config.ticket_mark, Action::ToggleMark);
+            map.insert(key_config.ticket_mark_all, Action::MarkAll);
+            map.insert(key_config.ticket_mark_range, Action::MarkRange);
             map.insert(key_config.scroll_down, Action::Next(1));
             map.insert(key_config.scroll_up, Action::Previous(1));
             map.insert(key_config.scroll_down_multiple_lines, Action::Next(10));
@@ -209,10 +267,13 @@ impl TicketWidget {
         Self {
             jira_domain,
             key_mappings,
+            marked: HashSet::new(),
+            range_start: None,
             scroll: 0,
             state,
             ticket_description: None,
             tickets: vec![],
+            title: None,
         }
     }
 
@@ -234,7 +295,7 @@ impl TicketWidget {
         let i = self
             .state
             .selected()
-            .map(|i| if i <= line { 0 } else { i - line });
+            .map(|i| i.saturating_sub(line));
 
         self.select(i);
     }
@@ -305,8 +366,58 @@ impl TicketWidget {
         Ok(())
     }
 
+    /// Keys of the marked tickets, in list order.
+    pub fn marked_keys(&self) -> Vec<String> {
+        self.tickets
+            .iter()
+            .filter(|ticket| self.marked.contains(&ticket.key))
+            .map(|ticket| ticket.key.clone())
+            .collect()
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.range_start = None;
+    }
+
+    fn toggle_mark(&mut self) {
+        let key = match self.selected() {
+            Some(ticket) => ticket.key.clone(),
+            None => return,
+        };
+        if !self.marked.remove(&key) {
+            self.marked.insert(key);
+        }
+    }
+
+    fn mark_all(&mut self) {
+        if self.marked_keys().len() == self.tickets.len() {
+            self.clear_marks();
+        } else {
+            self.marked = self.tickets.iter().map(|t| t.key.clone()).collect();
+        }
+    }
+
+    fn marked_range(&self) -> Option<std::ops::RangeInclusive<usize>> {
+        let start = self.range_start?;
+        let end = self.state.selected()?;
+        Some(start.min(end)..=start.max(end))
+    }
+
+    /// First press remembers where the range starts, the second marks every ticket up to here.
+    fn mark_range(&mut self) {
+        match self.marked_range() {
+            Some(range) => {
+                let keys: Vec<String> = self.tickets[range].iter().map(|t| t.key.clone()).collect();
+                self.marked.extend(keys);
+                self.range_start = None;
+            }
+            None => self.range_start = self.state.selected(),
+        }
+    }
+
     pub fn scroll_down_description(&mut self, lines: u16) {
-        if let Some(..) = self.selected() {
+        if self.selected().is_some() {
             self.scroll = self.scroll.saturating_add(lines);
             if self.scroll >= 100 {
                 self.scroll = 0
@@ -326,6 +437,8 @@ impl TicketWidget {
         if clear {
             self.tickets.clear();
         }
+        self.ticket_description = None;
+        self.range_start = None;
         self.tickets.append(&mut tickets);
         Ok(())
     }
@@ -338,6 +451,8 @@ impl Component for TicketWidget {
         if let Some(action) = self.key_mappings.get(&key) {
             use Action::*;
             match *action {
+                MarkAll => self.mark_all(),
+                MarkRange => self.mark_range(),
                 OpenBrowser => self.open_browser(),
                 Next(line) => self.next(line),
                 Previous(line) => self.previous(line),
@@ -345,6 +460,7 @@ impl Component for TicketWidget {
                 First => self.go_to_top(),
                 ScrollDownDescription(line) => self.scroll_down_description(line),
                 ScrollUpDescription(line) => self.scroll_up_description(line),
+                ToggleMark => self.toggle_mark(),
             }
             Ok(EventState::Consumed)
         } else {
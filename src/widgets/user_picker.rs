@@ -0,0 +1,259 @@
+use simsearch::SimSearch;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{event::key::Key, jira::users::User};
+
+use super::{draw_highlight_style, EventState, InputMode};
+
+#[derive(Debug, Clone)]
+pub enum UserPickerSelection {
+    Myself,
+    Nobody,
+    User(User),
+}
+
+pub struct UserPickerWidget {
+    filtered_users: Vec<usize>,
+    nobody: Option<String>,
+    state: ListState,
+    title: String,
+    users: Vec<User>,
+    pub input: String,
+    pub input_mode: InputMode,
+    pub search_users: bool,
+    pub selection: Option<UserPickerSelection>,
+}
+
+impl UserPickerWidget {
+    pub fn new() -> Self {
+        Self {
+            filtered_users: Vec::new(),
+            nobody: None,
+            input: String::new(),
+            input_mode: InputMode::Normal,
+            search_users: false,
+            selection: None,
+            state: ListState::default(),
+            title: String::new(),
+            users: Vec::new(),
+        }
+    }
+
+    /// `nobody` describes picking no user, e.g. "unassign", and hides that choice when `None`.
+    pub fn open(&mut self, title: &str, users: Vec<User>, nobody: Option<&str>) {
+        self.title = title.to_string();
+        self.nobody = nobody.map(str::to_string);
+        self.input.clear();
+        self.input_mode = InputMode::Editing;
+        self.selection = None;
+        self.update(users);
+    }
+
+    pub fn update(&mut self, users: Vec<User>) {
+        self.users = users;
+        self.filter();
+    }
+
+    fn filter(&mut self) {
+        if self.input.is_empty() {
+            self.filtered_users = (0..self.users.len()).collect();
+        } else {
+            let mut engine: SimSearch<usize> = SimSearch::new();
+            for (index, user) in self.users.iter().enumerate() {
+                let email = user.email_address.as_deref().unwrap_or_default();
+                engine.insert_tokens(index, &[user.display_name.as_str(), email]);
+            }
+            self.filtered_users = engine.search(&self.input);
+        }
+        let selected = if self.filtered_users.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.state.select(selected);
+    }
+
+    pub fn selected(&self) -> Option<&User> {
+        match self.state.selected() {
+            Some(i) => self
+                .filtered_users
+                .get(i)
+                .and_then(|index| self.users.get(*index)),
+            None => None,
+        }
+    }
+
+    pub fn next(&mut self, line: usize) {
+        if self.filtered_users.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.filtered_users.len() - 1));
+        self.state.select(i);
+    }
+
+    pub fn previous(&mut self, line: usize) {
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
+        self.state.select(i);
+    }
+}
+
+impl UserPickerWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let chunk_constrains = [
+            Constraint::Length(1),
+            Constraint::Length(3),
+            Constraint::Min(1),
+        ]
+        .as_ref();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints(chunk_constrains)
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let mut normal_mode_msg = vec![
+            Span::raw("Press "),
+            Span::styled("ESC", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" to exit, "),
+            Span::styled("e", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" to start editing, "),
+            Span::styled("m", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" to pick me"),
+        ];
+        if let Some(nobody) = &self.nobody {
+            normal_mode_msg.push(Span::raw(", "));
+            normal_mode_msg.push(Span::styled(
+                "u",
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+            normal_mode_msg.push(Span::raw(format!(" to {}", nobody)));
+        }
+        normal_mode_msg.push(Span::raw("."));
+        let normal_mode_style = (
+            normal_mode_msg,
+            Style::default().add_modifier(Modifier::UNDERLINED),
+        );
+        let edit_mode_style = (
+            vec![
+                Span::raw("Press "),
+                Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to stop editing. "),
+                Span::styled("Up/Down", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to move. "),
+                Span::styled("Return", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to select user, or search Jira when nothing matches."),
+            ],
+            Style::default(),
+        );
+        let (msg, style) = match self.input_mode {
+            InputMode::Normal => normal_mode_style,
+            InputMode::Editing => edit_mode_style,
+        };
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(style);
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        let input = Paragraph::new(self.input.as_ref())
+            .wrap(Wrap { trim: true })
+            .style(match self.input_mode {
+                InputMode::Normal => Style::default(),
+                InputMode::Editing => Style::default().fg(Color::Yellow),
+            })
+            .block(Block::default().borders(Borders::ALL).title("Search"));
+        f.render_widget(input, chunks[1]);
+
+        let list_items: Vec<ListItem> = self
+            .filtered_users
+            .iter()
+            .filter_map(|index| self.users.get(*index))
+            .map(|user| match &user.email_address {
+                Some(email) => ListItem::new(format!("{} <{}>", user.display_name, email)),
+                None => ListItem::new(user.display_name.as_str()),
+            })
+            .collect();
+        let list = List::new(list_items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.title.as_str()),
+            )
+            .highlight_style(draw_highlight_style());
+        f.render_stateful_widget(list, chunks[2], &mut self.state);
+
+        if self.input_mode == InputMode::Editing {
+            f.set_cursor(chunks[1].x + self.input.len() as u16 + 1, chunks[1].y + 1)
+        }
+
+        Ok(())
+    }
+}
+
+impl UserPickerWidget {
+    fn movement(&mut self, key: Key) -> anyhow::Result<EventState> {
+        match key {
+            Key::Down | Key::Tab => self.next(1),
+            Key::Up | Key::BackTab => self.previous(1),
+            Key::Ctrl('d') => self.next(10),
+            Key::Ctrl('u') => self.previous(10),
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+
+    fn select(&mut self) {
+        match self.selected() {
+            Some(user) => self.selection = Some(UserPickerSelection::User(user.clone())),
+            None => self.search_users = !self.input.is_empty(),
+        }
+    }
+
+    fn normal_mode_key_event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        match key {
+            Key::Char('e') => self.input_mode = InputMode::Editing,
+            Key::Char('j') => self.next(1),
+            Key::Char('k') => self.previous(1),
+            Key::Char('m') => self.selection = Some(UserPickerSelection::Myself),
+            Key::Char('u') if self.nobody.is_some() => {
+                self.selection = Some(UserPickerSelection::Nobody)
+            }
+            Key::Enter => self.select(),
+            _ => return self.movement(key),
+        }
+        Ok(EventState::Consumed)
+    }
+
+    fn edit_mode_key_event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        match key {
+            Key::Char(c) => {
+                self.input.push(c);
+                self.filter();
+            }
+            Key::Backspace => {
+                self.input.pop();
+                self.filter();
+            }
+            Key::Esc => self.input_mode = InputMode::Normal,
+            Key::Enter => self.select(),
+            _ => return self.movement(key),
+        }
+        Ok(EventState::Consumed)
+    }
+
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        match self.input_mode {
+            InputMode::Normal => self.normal_mode_key_event(key),
+            InputMode::Editing => self.edit_mode_key_event(key),
+        }
+    }
+}
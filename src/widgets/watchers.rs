@@ -0,0 +1,135 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{
+    config::KeyConfig,
+    event::key::Key,
+    jira::{tickets::Watchers, users::User},
+};
+
+use super::{draw_block_style, draw_highlight_style, EventState};
+
+pub struct WatchersWidget {
+    is_watching: bool,
+    key_config: KeyConfig,
+    state: ListState,
+    ticket_key: String,
+    watchers: Vec<User>,
+}
+
+impl WatchersWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>, focused: bool) -> anyhow::Result<()> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([Constraint::Length(1), Constraint::Min(1)].as_ref())
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let msg = vec![
+            Span::raw("Press "),
+            Span::styled("ESC", bold),
+            Span::raw(" to exit, "),
+            Span::styled(self.key_config.ticket_watch.to_string(), bold),
+            Span::raw(" to watch/unwatch, "),
+            Span::styled(self.key_config.add.to_string(), bold),
+            Span::raw(" to add a watcher, "),
+            Span::styled(self.key_config.remove.to_string(), bold),
+            Span::raw(" to remove the selected watcher."),
+        ];
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(Style::default().add_modifier(Modifier::UNDERLINED));
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        let title = match self.is_watching {
+            true => format!("{} watchers (watching)", self.ticket_key),
+            false => format!("{} watchers (not watching)", self.ticket_key),
+        };
+        let list_items: Vec<ListItem> = self
+            .watchers
+            .iter()
+            .map(|user| ListItem::new(user.display_name.as_str()))
+            .collect();
+        let list = List::new(list_items)
+            .block(draw_block_style(focused, &title))
+            .highlight_style(draw_highlight_style());
+        f.render_stateful_widget(list, chunks[1], &mut self.state);
+
+        Ok(())
+    }
+}
+
+impl WatchersWidget {
+    pub fn new(key_config: KeyConfig) -> Self {
+        Self {
+            is_watching: false,
+            key_config,
+            state: ListState::default(),
+            ticket_key: String::new(),
+            watchers: Vec::new(),
+        }
+    }
+
+    pub fn update(&mut self, ticket_key: &str, watchers: Watchers) {
+        self.ticket_key = ticket_key.to_string();
+        self.is_watching = watchers.is_watching;
+        self.watchers = watchers.watchers;
+        let selected = match self.state.selected() {
+            _ if self.watchers.is_empty() => None,
+            Some(i) => Some(i.min(self.watchers.len() - 1)),
+            None => Some(0),
+        };
+        self.state.select(selected);
+    }
+
+    pub fn is_watching(&self) -> bool {
+        self.is_watching
+    }
+
+    pub fn selected(&self) -> Option<&User> {
+        match self.state.selected() {
+            Some(i) => self.watchers.get(i),
+            None => None,
+        }
+    }
+
+    pub fn next(&mut self, line: usize) {
+        if self.watchers.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.watchers.len() - 1));
+        self.state.select(i);
+    }
+
+    pub fn previous(&mut self, line: usize) {
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
+        self.state.select(i);
+    }
+}
+
+impl WatchersWidget {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if key == self.key_config.scroll_down || key == self.key_config.move_down {
+            self.next(1);
+        } else if key == self.key_config.scroll_up || key == self.key_config.move_up {
+            self.previous(1);
+        } else if key == self.key_config.scroll_down_multiple_lines {
+            self.next(10);
+        } else if key == self.key_config.scroll_up_multiple_lines {
+            self.previous(10);
+        } else {
+            return Ok(EventState::NotConsumed);
+        }
+        Ok(EventState::Consumed)
+    }
+}
@@ -0,0 +1,134 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{event::key::Key, jira::tickets::is_jira_duration};
+
+use super::{draw_block_style, EventState};
+
+/// Popup form with a time spent and a comment input.
+pub struct WorklogAdd {
+    comment_focused: bool,
+    invalid: bool,
+    pub comment: String,
+    pub push_worklog: bool,
+    pub time_spent: String,
+}
+
+impl WorklogAdd {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>) -> anyhow::Result<()> {
+        let width = 80;
+        let height = 12;
+        let area = Rect::new(
+            (f.size().width.saturating_sub(width)) / 2,
+            (f.size().height.saturating_sub(height)) / 2,
+            width.min(f.size().width),
+            height.min(f.size().height),
+        );
+        f.render_widget(Clear, area);
+        f.render_widget(draw_block_style(true, "Log work"), area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Length(3),
+                    Constraint::Min(3),
+                ]
+                .as_ref(),
+            )
+            .split(area);
+
+        let msg = if self.invalid {
+            vec![Span::styled(
+                "Time spent must look like 1w 2d 3h 30m",
+                Style::default().fg(Color::Red),
+            )]
+        } else {
+            vec![
+                Span::raw("Press "),
+                Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to switch field, "),
+                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to log work, "),
+                Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" to cancel."),
+            ]
+        };
+        f.render_widget(Paragraph::new(Text::from(Spans::from(msg))), chunks[0]);
+
+        let input_style = |focused: bool| match focused {
+            true => Style::default().fg(Color::Yellow),
+            false => Style::default(),
+        };
+        let time_spent = Paragraph::new(self.time_spent.as_ref())
+            .style(input_style(!self.comment_focused))
+            .block(Block::default().borders(Borders::ALL).title("Time spent"));
+        f.render_widget(time_spent, chunks[1]);
+
+        let comment = Paragraph::new(self.comment.as_ref())
+            .wrap(Wrap { trim: true })
+            .style(input_style(self.comment_focused))
+            .block(Block::default().borders(Borders::ALL).title("Comment"));
+        f.render_widget(comment, chunks[2]);
+
+        let (chunk, input) = match self.comment_focused {
+            true => (chunks[2], &self.comment),
+            false => (chunks[1], &self.time_spent),
+        };
+        f.set_cursor(chunk.x + input.len() as u16 + 1, chunk.y + 1);
+
+        Ok(())
+    }
+}
+
+impl WorklogAdd {
+    pub fn new() -> Self {
+        Self {
+            comment: String::new(),
+            comment_focused: false,
+            invalid: false,
+            push_worklog: false,
+            time_spent: String::new(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.comment.clear();
+        self.comment_focused = false;
+        self.invalid = false;
+        self.push_worklog = false;
+        self.time_spent.clear();
+    }
+
+    fn input(&mut self) -> &mut String {
+        match self.comment_focused {
+            true => &mut self.comment,
+            false => &mut self.time_spent,
+        }
+    }
+}
+
+impl WorklogAdd {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        match key {
+            Key::Char(c) => self.input().push(c),
+            Key::Backspace => {
+                self.input().pop();
+            }
+            Key::Tab | Key::BackTab => self.comment_focused = !self.comment_focused,
+            Key::Enter => {
+                self.invalid = !is_jira_duration(&self.time_spent);
+                self.push_worklog = !self.invalid;
+            }
+            _ => return Ok(EventState::NotConsumed),
+        }
+        Ok(EventState::Consumed)
+    }
+}
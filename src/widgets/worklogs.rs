@@ -0,0 +1,154 @@
+use chrono::DateTime;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Span, Spans, Text},
+    widgets::{Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    config::KeyConfig,
+    event::key::Key,
+    jira::tickets::{adf_to_text, Worklog, Worklogs},
+};
+
+use super::{draw_block_style, draw_highlight_style, EventState};
+
+pub struct WorklogWidget {
+    key_config: KeyConfig,
+    state: TableState,
+    title: String,
+    worklogs: Vec<Worklog>,
+}
+
+impl WorklogWidget {
+    pub fn draw<B: Backend>(&mut self, f: &mut Frame<B>, focused: bool) -> anyhow::Result<()> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([Constraint::Length(1), Constraint::Min(1)].as_ref())
+            .split(f.size());
+        f.render_widget(Clear, f.size());
+
+        let msg = vec![
+            Span::raw("Press "),
+            Span::styled("ESC", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" to exit, "),
+            Span::styled(
+                self.key_config.add.to_string(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" to log time."),
+        ];
+        let mut text = Text::from(Spans::from(msg));
+        text.patch_style(Style::default().add_modifier(Modifier::UNDERLINED));
+        f.render_widget(Paragraph::new(text), chunks[0]);
+
+        let headers = Row::new(["Author", "Time spent", "Started", "Comment"]);
+        let rows = self.worklogs.iter().map(|worklog| {
+            let started = DateTime::parse_from_str(&worklog.started, "%Y-%m-%dT%H:%M:%S%.3f%z")
+                .map(|started| started.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_else(|_| worklog.started.clone());
+            let comment = match &worklog.comment {
+                Some(c) => adf_to_text(c).trim_end().to_string(),
+                None => String::new(),
+            };
+            let height = comment.lines().count().max(1);
+            Row::new([
+                Cell::from(worklog.author.display_name.clone()),
+                Cell::from(worklog.time_spent.clone()),
+                Cell::from(started),
+                Cell::from(comment),
+            ])
+            .height(height as u16)
+        });
+        let table = Table::new(rows)
+            .header(headers)
+            .block(draw_block_style(focused, &self.title))
+            .highlight_style(draw_highlight_style())
+            .widths(&[
+                Constraint::Percentage(20),
+                Constraint::Percentage(10),
+                Constraint::Percentage(20),
+                Constraint::Percentage(50),
+            ]);
+        f.render_stateful_widget(table, chunks[1], &mut self.state);
+
+        Ok(())
+    }
+}
+
+impl WorklogWidget {
+    pub fn new(key_config: KeyConfig) -> Self {
+        Self {
+            key_config,
+            state: TableState::default(),
+            title: String::new(),
+            worklogs: Vec::new(),
+        }
+    }
+
+    pub fn update(&mut self, ticket_key: &str, worklogs: Worklogs) {
+        self.title = format!("{} worklogs", ticket_key);
+        self.worklogs = worklogs.worklogs;
+        let selected = if self.worklogs.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.state.select(selected);
+    }
+
+    pub fn next(&mut self, line: usize) {
+        if self.worklogs.is_empty() {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map(|i| (i + line).min(self.worklogs.len() - 1));
+        self.state.select(i);
+    }
+
+    pub fn previous(&mut self, line: usize) {
+        let i = self.state.selected().map(|i| i.saturating_sub(line));
+        self.state.select(i);
+    }
+
+    pub fn go_to_top(&mut self) {
+        if self.worklogs.is_empty() {
+            return;
+        }
+        self.state.select(Some(0));
+    }
+
+    pub fn go_to_bottom(&mut self) {
+        if self.worklogs.is_empty() {
+            return;
+        }
+        self.state.select(Some(self.worklogs.len() - 1));
+    }
+}
+
+impl WorklogWidget {
+    pub fn event(&mut self, key: Key) -> anyhow::Result<EventState> {
+        if key == self.key_config.scroll_down {
+            self.next(1);
+        } else if key == self.key_config.scroll_up {
+            self.previous(1);
+        } else if key == self.key_config.scroll_down_multiple_lines {
+            self.next(10);
+        } else if key == self.key_config.scroll_up_multiple_lines {
+            self.previous(10);
+        } else if key == self.key_config.scroll_to_bottom {
+            self.go_to_bottom();
+        } else if key == self.key_config.scroll_to_top {
+            self.go_to_top();
+        } else {
+            return Ok(EventState::NotConsumed);
+        }
+        Ok(EventState::Consumed)
+    }
+}